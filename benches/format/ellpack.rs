@@ -0,0 +1,53 @@
+use test::Bencher;
+
+use matrix::format::compressed::Variant;
+use matrix::format::ellpack::Sliced;
+use matrix::format::{Compressed, Ellpack};
+use matrix::operation::MultiplyInto;
+
+#[bench]
+fn multiply_compressed_0100(b: &mut Bencher) {
+    let matrix = laplacian(100);
+    multiply(&matrix, b);
+}
+
+#[bench]
+fn multiply_ellpack_0100(b: &mut Bencher) {
+    let matrix = Ellpack::from(laplacian(100));
+    multiply(&matrix, b);
+}
+
+#[bench]
+fn multiply_sliced_0100(b: &mut Bencher) {
+    let matrix = Sliced::from_compressed(&laplacian(100), 8, 64).unwrap();
+    multiply(&matrix, b);
+}
+
+fn multiply<M: MultiplyInto<[f64], [f64]>>(matrix: &M, bencher: &mut Bencher) {
+    let right = vec![1.0; 100 * 100];
+    let mut result = vec![0.0; 100 * 100];
+    bencher.iter(|| matrix.multiply_into(&right, &mut result));
+}
+
+fn laplacian(size: usize) -> Compressed<f64> {
+    let mut matrix = Compressed::with_capacity(size * size, Variant::Column, 5 * size * size);
+    for i in 0..size {
+        for j in 0..size {
+            let k = i * size + j;
+            matrix.set((k, k), 4.0);
+            if i > 0 {
+                matrix.set((k, k - size), -1.0);
+            }
+            if j > 0 {
+                matrix.set((k, k - 1), -1.0);
+            }
+            if j + 1 < size {
+                matrix.set((k, k + 1), -1.0);
+            }
+            if i + 1 < size {
+                matrix.set((k, k + size), -1.0);
+            }
+        }
+    }
+    matrix
+}
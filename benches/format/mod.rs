@@ -1,2 +1,3 @@
 mod compressed;
 mod conventional;
+mod ellpack;
@@ -1,5 +1,7 @@
 //! Decompositions.
 
+use num_traits::Float;
+
 use format::{Conventional, Diagonal};
 use {Element, Result};
 
@@ -14,3 +16,140 @@ pub trait SymmetricEigen<T: Element> {
     /// Perform the decomposition.
     fn decompose(&self) -> Result<(Conventional<T>, Diagonal<T>)>;
 }
+
+/// A factorization capable of estimating the condition number.
+///
+/// The estimation follows the algorithm of Hager with the refinements of
+/// Higham as done by [`lacon`][1] in LAPACK. It requires a few solutions with
+/// the factorization and its transpose and never forms the inverse.
+///
+/// [1]: http://www.netlib.org/lapack/explore-html/d6/d49/dlacon_8f.html
+pub trait Condition<T: Element + Float>: Solve<T> {
+    /// Return the number of rows or columns of the factorized matrix.
+    fn order(&self) -> usize;
+
+    /// Estimate the 1-norm of the inverse of the factorized matrix.
+    fn estimate_inverse_norm(&self) -> f64 {
+        estimate(self, self.order())
+    }
+
+    /// Estimate the 1-norm condition number given the 1-norm of the
+    /// factorized matrix.
+    #[inline]
+    fn estimate_condition(&self, norm: f64) -> f64 {
+        norm * self.estimate_inverse_norm()
+    }
+}
+
+/// A factorization capable of solving linear systems.
+pub trait Solve<T: Element> {
+    /// Solve `A × X = B` overwriting `B` with `X`.
+    ///
+    /// The right-hand sides are stored in the column-major order.
+    fn solve(&self, right: &mut [T]);
+
+    /// Solve `Aᵀ × X = B` overwriting `B` with `X`.
+    ///
+    /// The right-hand sides are stored in the column-major order.
+    fn solve_transpose(&self, right: &mut [T]);
+}
+
+fn estimate<T, S>(factorization: &S, n: usize) -> f64
+where
+    T: Element + Float,
+    S: Solve<T> + ?Sized,
+{
+    const ITERATIONS: usize = 5;
+    if n == 0 {
+        return 0.0;
+    }
+    let (zero, one): (T, T) = (Element::zero(), Element::one());
+    let norm = |x: &[T]| x.iter().fold(0.0, |sum, value| sum + value.magnitude());
+    let sign = |value: T| -> T {
+        if value >= zero {
+            one
+        } else {
+            -one
+        }
+    };
+    let mut x = vec![T::from(1.0 / n as f64).unwrap(); n];
+    factorization.solve(&mut x);
+    let mut estimate = norm(&x);
+    if n == 1 {
+        return estimate;
+    }
+    let mut signs = x.iter().map(|&value| sign(value)).collect::<Vec<_>>();
+    let mut j = 0;
+    for iteration in 0..ITERATIONS {
+        let mut z = signs.clone();
+        factorization.solve_transpose(&mut z);
+        let k = (0..n).fold(0, |k, i| if z[i].abs() > z[k].abs() { i } else { k });
+        if iteration > 0 && z[k].abs() <= z[j].abs() {
+            break;
+        }
+        j = k;
+        let mut x = vec![zero; n];
+        x[j] = one;
+        factorization.solve(&mut x);
+        let previous = estimate;
+        estimate = norm(&x);
+        let next = x.iter().map(|&value| sign(value)).collect::<Vec<_>>();
+        if next == signs || estimate <= previous {
+            estimate = estimate.max(previous);
+            break;
+        }
+        signs = next;
+    }
+    let mut x = (0..n)
+        .map(|i| {
+            let value = 1.0 + i as f64 / (n - 1) as f64;
+            T::from(if i % 2 == 0 { value } else { -value }).unwrap()
+        })
+        .collect::<Vec<_>>();
+    factorization.solve(&mut x);
+    estimate.max(2.0 * norm(&x) / (3.0 * n as f64))
+}
+
+#[cfg(test)]
+mod tests {
+    use assert;
+
+    use decomposition::Condition;
+    use format::banded::Symmetric;
+    use format::packed::Variant;
+    use prelude::*;
+
+    #[test]
+    fn estimate_condition() {
+        let matrix = Banded::from(Conventional::from_vec(
+            3,
+            matrix![
+                4.0, 1.0, 0.0;
+                2.0, 5.0, 1.0;
+                0.0, 3.0, 6.0;
+            ],
+        ));
+        let lu = matrix.lu().unwrap();
+        // The exact 1-norm of the inverse is 15 / 32.
+        assert::close(lu.estimate_inverse_norm(), 15.0 / 32.0, 1e-14);
+        assert::close(
+            lu.estimate_condition(matrix.norm_one()),
+            135.0 / 32.0,
+            1e-14,
+        );
+
+        let matrix = Banded::from(Conventional::from_vec(
+            4,
+            matrix![
+                 2.0, -1.0,  0.0,  0.0;
+                -1.0,  2.0, -1.0,  0.0;
+                 0.0, -1.0,  2.0, -1.0;
+                 0.0,  0.0, -1.0,  2.0;
+            ],
+        ));
+        let matrix = Symmetric::from_banded(&matrix, Variant::Lower).unwrap();
+        let cholesky = matrix.cholesky().unwrap();
+        // The exact 1-norm of the inverse is 3.
+        assert::close(cholesky.estimate_inverse_norm(), 3.0, 1e-12);
+    }
+}
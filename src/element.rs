@@ -5,44 +5,97 @@ pub trait Element: Copy + PartialEq {
     /// Return the zero element.
     fn zero() -> Self;
 
+    /// Return the unit element.
+    fn one() -> Self;
+
     /// Check if the element is zero.
     #[inline(always)]
     fn is_zero(&self) -> bool {
         self == &Self::zero()
     }
+
+    /// Return the absolute value or modulus as a double-precision number.
+    fn magnitude(&self) -> f64;
+
+    /// Return the complex conjugate, which is the element itself unless the
+    /// element is complex.
+    #[inline(always)]
+    fn conj(&self) -> Self {
+        *self
+    }
 }
 
 macro_rules! implement(
-    ($name:ty, $zero:expr) => (
+    ($name:ty, $zero:expr, $one:expr, $value:ident => $magnitude:expr) => (
         impl Element for $name {
             #[inline(always)]
             fn zero() -> Self {
                 $zero
             }
+
+            #[inline(always)]
+            fn one() -> Self {
+                $one
+            }
+
+            #[inline(always)]
+            fn magnitude(&self) -> f64 {
+                let $value = *self;
+                $magnitude
+            }
         }
     );
-    ($name:ty) => (
-        implement!($name, 0);
+    ($name:ty, unsigned) => (
+        implement!($name, 0, 1, value => value as f64);
+    );
+    ($name:ty, signed) => (
+        implement!($name, 0, 1, value => (value as f64).abs());
+    );
+);
+
+macro_rules! implement_complex(
+    ($name:ident) => (
+        impl Element for $name {
+            #[inline(always)]
+            fn zero() -> Self {
+                $name::new(0.0, 0.0)
+            }
+
+            #[inline(always)]
+            fn one() -> Self {
+                $name::new(1.0, 0.0)
+            }
+
+            #[inline(always)]
+            fn magnitude(&self) -> f64 {
+                (self.re as f64).hypot(self.im as f64)
+            }
+
+            #[inline(always)]
+            fn conj(&self) -> Self {
+                $name::new(self.re, -self.im)
+            }
+        }
     );
 );
 
-implement!(bool, false);
+implement!(bool, false, true, value => if value { 1.0 } else { 0.0 });
 
-implement!(u8);
-implement!(u16);
-implement!(u32);
-implement!(u64);
+implement!(u8, unsigned);
+implement!(u16, unsigned);
+implement!(u32, unsigned);
+implement!(u64, unsigned);
 
-implement!(i8);
-implement!(i16);
-implement!(i32);
-implement!(i64);
+implement!(i8, signed);
+implement!(i16, signed);
+implement!(i32, signed);
+implement!(i64, signed);
 
-implement!(f32, 0.0);
-implement!(f64, 0.0);
+implement!(f32, 0.0, 1.0, value => (value as f64).abs());
+implement!(f64, 0.0, 1.0, value => value.abs());
 
-implement!(isize);
-implement!(usize);
+implement!(isize, signed);
+implement!(usize, unsigned);
 
-implement!(c32, c32::new(0.0, 0.0));
-implement!(c64, c64::new(0.0, 0.0));
+implement_complex!(c32);
+implement_complex!(c64);
@@ -1,6 +1,45 @@
-use format::{Banded, Conventional, Diagonal};
+use std::iter;
+
+use format::compressed::Variant;
+use format::{Banded, Compressed, Conventional, Diagonal};
 use Element;
 
+impl<'l, T: Element> From<&'l Banded<T>> for Compressed<T> {
+    /// Convert into a compressed matrix in the compressed-column variant.
+    ///
+    /// The zero elements are skipped.
+    fn from(matrix: &'l Banded<T>) -> Self {
+        let &Banded {
+            rows,
+            columns,
+            superdiagonals,
+            subdiagonals,
+            ref values,
+        } = validate!(matrix);
+        let diagonals = matrix.diagonals();
+        let mut result = Compressed::new((rows, columns), Variant::Column);
+        for j in 0..columns {
+            for i in row_range!(rows, superdiagonals, subdiagonals, j) {
+                let value = values[j * diagonals + superdiagonals + i - j];
+                if !value.is_zero() {
+                    result.values.push(value);
+                    result.indices.push(i);
+                }
+            }
+            result.offsets[j + 1] = result.values.len();
+        }
+        result.nonzeros = result.values.len();
+        result
+    }
+}
+
+impl<T: Element> From<Banded<T>> for Compressed<T> {
+    #[inline]
+    fn from(matrix: Banded<T>) -> Self {
+        (&matrix).into()
+    }
+}
+
 impl<'l, T: Element> From<&'l Banded<T>> for Conventional<T> {
     fn from(matrix: &'l Banded<T>) -> Self {
         let &Banded {
@@ -29,6 +68,50 @@ impl<T: Element> From<Banded<T>> for Conventional<T> {
     }
 }
 
+impl<'l, T: Element> From<&'l Compressed<T>> for Banded<T> {
+    /// Convert from a compressed matrix.
+    ///
+    /// The numbers of superdiagonals and subdiagonals are the smallest ones
+    /// covering all stored elements.
+    fn from(matrix: &'l Compressed<T>) -> Self {
+        from_triplets(matrix.rows, matrix.columns, || {
+            matrix.iter().map(|(i, j, &value)| (i, j, value))
+        })
+    }
+}
+
+impl<T: Element> From<Compressed<T>> for Banded<T> {
+    #[inline]
+    fn from(matrix: Compressed<T>) -> Self {
+        (&matrix).into()
+    }
+}
+
+impl<'l, T: Element> From<&'l Conventional<T>> for Banded<T> {
+    /// Convert from a conventional matrix.
+    ///
+    /// The numbers of superdiagonals and subdiagonals are the smallest ones
+    /// covering all nonzero elements.
+    fn from(matrix: &'l Conventional<T>) -> Self {
+        let rows = matrix.rows;
+        from_triplets(rows, matrix.columns, || {
+            matrix
+                .values
+                .iter()
+                .enumerate()
+                .filter(|&(_, value)| !value.is_zero())
+                .map(move |(k, &value)| (k % rows, k / rows, value))
+        })
+    }
+}
+
+impl<T: Element> From<Conventional<T>> for Banded<T> {
+    #[inline]
+    fn from(matrix: Conventional<T>) -> Self {
+        (&matrix).into()
+    }
+}
+
 impl<'l, T: Element> From<&'l Diagonal<T>> for Banded<T> {
     #[inline]
     fn from(matrix: &'l Diagonal<T>) -> Self {
@@ -50,10 +133,96 @@ impl<T: Element> From<Diagonal<T>> for Banded<T> {
     }
 }
 
+pub(super) fn from_triplets<T, F, I>(rows: usize, columns: usize, triplets: F) -> Banded<T>
+where
+    T: Element,
+    F: Fn() -> I,
+    I: iter::Iterator<Item = (usize, usize, T)>,
+{
+    let (mut superdiagonals, mut subdiagonals) = (0, 0);
+    for (i, j, _) in triplets() {
+        if i < j {
+            superdiagonals = max!(superdiagonals, j - i);
+        } else {
+            subdiagonals = max!(subdiagonals, i - j);
+        }
+    }
+    let mut matrix = Banded::new((rows, columns), superdiagonals, subdiagonals);
+    let diagonals = matrix.diagonals();
+    for (i, j, value) in triplets() {
+        matrix.values[j * diagonals + superdiagonals + i - j] = value;
+    }
+    matrix
+}
+
 #[cfg(test)]
 mod tests {
+    use format::compressed::Variant;
     use prelude::*;
 
+    #[test]
+    fn from_compressed() {
+        let matrix = Compressed::from_parts(
+            (4, 5),
+            Variant::Column,
+            vec![1.0, 2.0, 3.0, 4.0, 5.0],
+            vec![0, 2, 1, 3, 0],
+            vec![0, 1, 2, 4, 4, 5],
+        )
+        .unwrap();
+        let matrix = Banded::from(&matrix);
+        assert_eq!((matrix.superdiagonals, matrix.subdiagonals), (4, 1));
+        assert_eq!(
+            &*Conventional::from(matrix),
+            &*matrix![
+                1.0, 0.0, 0.0, 0.0, 5.0;
+                0.0, 0.0, 3.0, 0.0, 0.0;
+                0.0, 2.0, 0.0, 0.0, 0.0;
+                0.0, 0.0, 4.0, 0.0, 0.0;
+            ]
+        );
+    }
+
+    #[test]
+    fn from_conventional() {
+        let matrix = Conventional::from_vec(
+            (4, 3),
+            matrix![
+                1.0, 0.0, 0.0;
+                2.0, 3.0, 0.0;
+                0.0, 0.0, 4.0;
+                5.0, 0.0, 0.0;
+            ],
+        );
+        let banded = Banded::from(&matrix);
+        assert_eq!((banded.superdiagonals, banded.subdiagonals), (0, 3));
+        assert_eq!(Conventional::from(banded), matrix);
+
+        let banded = Banded::from(Conventional::<f64>::new((2, 3)));
+        assert_eq!((banded.superdiagonals, banded.subdiagonals), (0, 0));
+    }
+
+    #[test]
+    fn into_compressed() {
+        let matrix = new!(
+            4,
+            3,
+            1,
+            2,
+            matrix![
+                0.0, 4.0, 7.0;
+                1.0, 0.0, 8.0;
+                2.0, 6.0, 9.0;
+                3.0, 0.0, 0.0;
+            ]
+        );
+        let matrix = Compressed::from(matrix);
+        assert_eq!(matrix.variant, Variant::Column);
+        assert_eq!(&matrix.values, &[1.0, 2.0, 3.0, 4.0, 6.0, 7.0, 8.0, 9.0]);
+        assert_eq!(&matrix.indices, &[0, 1, 2, 0, 2, 1, 2, 3]);
+        assert_eq!(&matrix.offsets, &[0, 3, 5, 8]);
+    }
+
     #[test]
     fn from_diagonal_tall() {
         let matrix = Banded::from(Diagonal::from_vec((5, 3), vec![1.0, 2.0, 3.0]));
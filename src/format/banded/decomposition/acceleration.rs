@@ -0,0 +1,108 @@
+use lapack as backend;
+
+use std::any::TypeId;
+use std::slice;
+
+use format::banded::Symmetric;
+use format::packed::Variant;
+use format::Banded;
+use {Element, Result};
+
+pub fn factorize<T>(factors: &mut Banded<T>, pivots: &mut [usize]) -> Option<Result<()>>
+where
+    T: 'static + Element,
+{
+    if TypeId::of::<T>() != TypeId::of::<f64>() {
+        return None;
+    }
+    let values = &mut factors.values;
+    let values =
+        unsafe { slice::from_raw_parts_mut(values.as_mut_ptr() as *mut f64, values.len()) };
+    Some(banded_lu(
+        values,
+        pivots,
+        factors.rows,
+        factors.superdiagonals - factors.subdiagonals,
+        factors.subdiagonals,
+    ))
+}
+
+pub fn factorize_symmetric<T>(factors: &mut Symmetric<T>) -> Option<Result<()>>
+where
+    T: 'static + Element,
+{
+    if TypeId::of::<T>() != TypeId::of::<f64>() {
+        return None;
+    }
+    let uplo = match factors.variant {
+        Variant::Lower => b'L',
+        Variant::Upper => b'U',
+    };
+    let values = &mut factors.values;
+    let values =
+        unsafe { slice::from_raw_parts_mut(values.as_mut_ptr() as *mut f64, values.len()) };
+    Some(banded_cholesky(
+        values,
+        uplo,
+        factors.size,
+        factors.offdiagonals,
+    ))
+}
+
+fn banded_cholesky(values: &mut [f64], uplo: u8, n: usize, kd: usize) -> Result<()> {
+    debug_assert_eq!(values.len(), (kd + 1) * n);
+    let mut info = 0;
+    unsafe {
+        backend::dpbtrf(
+            uplo,
+            n as i32,
+            kd as i32,
+            values,
+            (kd + 1) as i32,
+            &mut info,
+        );
+    }
+    if info < 0 {
+        raise!("encountered invalid arguments");
+    } else if info > 0 {
+        raise!(format!(
+            "the matrix is not positive definite (column {})",
+            info - 1
+        ));
+    }
+    Ok(())
+}
+
+fn banded_lu(
+    values: &mut [f64],
+    pivots: &mut [usize],
+    n: usize,
+    ku: usize,
+    kl: usize,
+) -> Result<()> {
+    debug_assert_eq!(values.len(), (2 * kl + ku + 1) * n);
+    debug_assert_eq!(pivots.len(), n);
+    let mut ipiv = vec![0; n];
+    let mut info = 0;
+    unsafe {
+        backend::dgbtrf(
+            n as i32,
+            n as i32,
+            kl as i32,
+            ku as i32,
+            values,
+            (2 * kl + ku + 1) as i32,
+            &mut ipiv,
+            &mut info,
+        );
+    }
+    if info < 0 {
+        raise!("encountered invalid arguments");
+    } else if info > 0 {
+        raise!(format!("the matrix is singular (column {})", info - 1));
+    }
+    for (pivot, &index) in pivots.iter_mut().zip(&ipiv) {
+        *pivot = index as usize - 1;
+    }
+    Ok(())
+}
@@ -0,0 +1,195 @@
+use num_traits::Float;
+
+use decomposition::{Condition, Solve};
+use format::banded::Symmetric;
+use {Element, Result};
+
+/// The Cholesky decomposition of a symmetric positive-definite banded matrix.
+///
+/// The decomposition is computed as done by [`pbtrf`][1] in LAPACK.
+///
+/// [1]: http://www.netlib.org/lapack/explore-html/df/da9/dpbtrf_8f.html
+#[derive(Clone, Debug, PartialEq)]
+pub struct Cholesky<T: Element> {
+    /// The factor stored in place of the band of the original matrix. When
+    /// `variant = Upper`, the factor is `U` such that `A = Uᵀ × U`. When
+    /// `variant = Lower`, the factor is `L` such that `A = L × Lᵀ`.
+    pub factors: Symmetric<T>,
+}
+
+impl<T> Symmetric<T>
+where
+    T: 'static + Element + Float,
+{
+    /// Perform the Cholesky decomposition.
+    ///
+    /// The function fails when the matrix is not positive definite.
+    pub fn cholesky(&self) -> Result<Cholesky<T>> {
+        let mut factors = validate!(self).clone();
+        #[cfg(feature = "acceleration")]
+        {
+            if let Some(result) = super::acceleration::factorize_symmetric(&mut factors) {
+                result?;
+                return Ok(Cholesky { factors });
+            }
+        }
+        factorize(&mut factors)?;
+        Ok(Cholesky { factors })
+    }
+}
+
+impl<T> Cholesky<T>
+where
+    T: Element + Float,
+{
+    /// Compute the natural logarithm of the determinant.
+    pub fn log_determinant(&self) -> T {
+        let factors = &self.factors;
+        let mut sum: T = Element::zero();
+        for j in 0..factors.size {
+            sum = sum + factors.values[factors.position(j, j)].ln();
+        }
+        sum + sum
+    }
+}
+
+impl<T> Condition<T> for Cholesky<T>
+where
+    T: Element + Float,
+{
+    #[inline]
+    fn order(&self) -> usize {
+        self.factors.size
+    }
+}
+
+impl<T> Solve<T> for Cholesky<T>
+where
+    T: Element + Float,
+{
+    fn solve(&self, right: &mut [T]) {
+        let factors = &self.factors;
+        let (n, kd) = (factors.size, factors.offdiagonals);
+        if n == 0 {
+            return;
+        }
+        debug_assert_eq!(right.len() % n, 0);
+        let values = &factors.values;
+        for b in right.chunks_mut(n) {
+            for j in 0..n {
+                let mut value = b[j];
+                for i in max_difference!(0, j, kd)..j {
+                    value = value - values[factors.position(i, j)] * b[i];
+                }
+                b[j] = value / values[factors.position(j, j)];
+            }
+            for j in (0..n).rev() {
+                b[j] = b[j] / values[factors.position(j, j)];
+                let value = b[j];
+                for i in max_difference!(0, j, kd)..j {
+                    b[i] = b[i] - values[factors.position(i, j)] * value;
+                }
+            }
+        }
+    }
+
+    #[inline]
+    fn solve_transpose(&self, right: &mut [T]) {
+        self.solve(right)
+    }
+}
+
+fn factorize<T>(factors: &mut Symmetric<T>) -> Result<()>
+where
+    T: Element + Float,
+{
+    let (n, kd) = (factors.size, factors.offdiagonals);
+    for j in 0..n {
+        let start = max_difference!(0, j, kd);
+        for i in start..(j + 1) {
+            let mut value = factors.values[factors.position(i, j)];
+            for k in start..i {
+                value = value
+                    - factors.values[factors.position(k, i)]
+                        * factors.values[factors.position(k, j)];
+            }
+            let position = factors.position(i, j);
+            if i < j {
+                factors.values[position] = value / factors.values[factors.position(i, i)];
+            } else if value > Element::zero() {
+                factors.values[position] = value.sqrt();
+            } else {
+                raise!(format!(
+                    "the matrix is not positive definite (column {})",
+                    j
+                ));
+            }
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use assert;
+    use format::banded::Symmetric;
+    use format::packed::Variant;
+    use prelude::*;
+
+    #[test]
+    fn cholesky() {
+        let matrix =
+            Symmetric::from_banded(&new!(2, 2, 1, 0, vec![0.0, 4.0, 2.0, 5.0]), Variant::Upper)
+                .unwrap();
+        let cholesky = matrix.cholesky().unwrap();
+        assert::close(&cholesky.factors.values, &[0.0, 2.0, 1.0, 2.0], 1e-14);
+    }
+
+    #[test]
+    fn cholesky_indefinite() {
+        let matrix = Symmetric::from_parts(2, 1, Variant::Lower, vec![1.0, 2.0, 1.0, 0.0]).unwrap();
+        assert!(matrix.cholesky().is_err());
+    }
+
+    #[test]
+    fn log_determinant() {
+        let matrix = Banded::from(Conventional::from_vec(
+            5,
+            matrix![
+                6.0, -4.0,  1.0,  0.0,  0.0;
+               -4.0,  6.0, -4.0,  1.0,  0.0;
+                1.0, -4.0,  6.0, -4.0,  1.0;
+                0.0,  1.0, -4.0,  6.0, -4.0;
+                0.0,  0.0,  1.0, -4.0,  6.0;
+            ],
+        ));
+        for &variant in &[Variant::Lower, Variant::Upper] {
+            let matrix = Symmetric::from_banded(&matrix, variant).unwrap();
+            let cholesky = matrix.cholesky().unwrap();
+            assert::close(&[cholesky.log_determinant()], &[196f64.ln()], 1e-12);
+        }
+    }
+
+    #[test]
+    fn solve() {
+        let matrix = Banded::from(Conventional::from_vec(
+            5,
+            matrix![
+                6.0, -4.0,  1.0,  0.0,  0.0;
+               -4.0,  6.0, -4.0,  1.0,  0.0;
+                1.0, -4.0,  6.0, -4.0,  1.0;
+                0.0,  1.0, -4.0,  6.0, -4.0;
+                0.0,  0.0,  1.0, -4.0,  6.0;
+            ],
+        ));
+        let expected = vec![1.0, -2.0, 3.0, -4.0, 5.0, 2.0, 1.0, 0.0, -1.0, 3.0];
+        let mut right = vec![0.0; 10];
+        matrix.multiply_into(&expected, &mut right);
+        for &variant in &[Variant::Lower, Variant::Upper] {
+            let mut right = right.clone();
+            let matrix = Symmetric::from_banded(&matrix, variant).unwrap();
+            matrix.cholesky().unwrap().solve(&mut right);
+            assert::close(&right, &expected, 1e-12);
+        }
+    }
+}
@@ -0,0 +1,315 @@
+use num_traits::Float;
+
+use decomposition::{Condition, Solve};
+use format::Banded;
+use {Element, Result};
+
+#[cfg(feature = "acceleration")]
+mod acceleration;
+mod cholesky;
+
+pub use self::cholesky::Cholesky;
+
+/// The LU decomposition of a banded matrix.
+///
+/// The decomposition is computed with partial pivoting as done by [`gbtrf`][1]
+/// in LAPACK.
+///
+/// [1]: http://www.netlib.org/lapack/explore-html/dd/d59/dgbtrf_8f.html
+#[derive(Clone, Debug, PartialEq)]
+pub struct Lu<T: Element> {
+    /// The factors stored in the banded format with `subdiagonals` additional
+    /// superdiagonals accommodating the fill-in. The upper triangle holds `U`,
+    /// and the subdiagonals hold the multipliers of `L`, whose diagonal is
+    /// implicitly one.
+    pub factors: Banded<T>,
+    /// The pivot indices such that the `i`th row was interchanged with the
+    /// `pivots[i]`th one.
+    pub pivots: Vec<usize>,
+}
+
+impl<T> Banded<T>
+where
+    T: 'static + Element + Float,
+{
+    /// Perform the LU decomposition.
+    ///
+    /// The matrix should be square. The function fails when the matrix is
+    /// singular.
+    pub fn lu(&self) -> Result<Lu<T>> {
+        let &Banded {
+            rows,
+            columns,
+            superdiagonals,
+            subdiagonals,
+            ref values,
+        } = validate!(self);
+        if rows != columns {
+            raise!(format!(
+                "the LU decomposition requires a square matrix but it is {} × {}",
+                rows, columns
+            ));
+        }
+        let mut factors = Banded::new(rows, superdiagonals + subdiagonals, subdiagonals);
+        let (from, into) = (self.diagonals(), factors.diagonals());
+        for j in 0..columns {
+            factors.values[(j * into + subdiagonals)..((j + 1) * into)]
+                .copy_from_slice(&values[(j * from)..((j + 1) * from)]);
+        }
+        let mut pivots = vec![0; rows];
+        #[cfg(feature = "acceleration")]
+        {
+            if let Some(result) = acceleration::factorize(&mut factors, &mut pivots) {
+                result?;
+                return Ok(Lu { factors, pivots });
+            }
+        }
+        factorize(&mut factors, &mut pivots)?;
+        Ok(Lu { factors, pivots })
+    }
+}
+
+impl<T> Lu<T>
+where
+    T: Element + Float,
+{
+    /// Compute the determinant.
+    pub fn determinant(&self) -> T {
+        let &Banded {
+            rows,
+            superdiagonals,
+            ref values,
+            ..
+        } = &self.factors;
+        let diagonals = self.factors.diagonals();
+        let mut product: T = Element::one();
+        for j in 0..rows {
+            product = product * values[j * diagonals + superdiagonals];
+            if self.pivots[j] != j {
+                product = -product;
+            }
+        }
+        product
+    }
+}
+
+impl<T> Condition<T> for Lu<T>
+where
+    T: Element + Float,
+{
+    #[inline]
+    fn order(&self) -> usize {
+        self.factors.rows
+    }
+}
+
+impl<T> Solve<T> for Lu<T>
+where
+    T: Element + Float,
+{
+    fn solve(&self, right: &mut [T]) {
+        let &Banded {
+            rows: n,
+            superdiagonals: kd,
+            subdiagonals: kl,
+            ref values,
+            ..
+        } = &self.factors;
+        if n == 0 {
+            return;
+        }
+        debug_assert_eq!(right.len() % n, 0);
+        let diagonals = self.factors.diagonals();
+        for b in right.chunks_mut(n) {
+            for j in 0..n {
+                let l = self.pivots[j];
+                if l != j {
+                    b.swap(l, j);
+                }
+                let value = b[j];
+                for i in (j + 1)..min!(n, j + kl + 1) {
+                    b[i] = b[i] - values[j * diagonals + kd + i - j] * value;
+                }
+            }
+            for j in (0..n).rev() {
+                b[j] = b[j] / values[j * diagonals + kd];
+                let value = b[j];
+                for i in max_difference!(0, j, kd)..j {
+                    b[i] = b[i] - values[j * diagonals + kd + i - j] * value;
+                }
+            }
+        }
+    }
+
+    fn solve_transpose(&self, right: &mut [T]) {
+        let &Banded {
+            rows: n,
+            superdiagonals: kd,
+            subdiagonals: kl,
+            ref values,
+            ..
+        } = &self.factors;
+        if n == 0 {
+            return;
+        }
+        debug_assert_eq!(right.len() % n, 0);
+        let diagonals = self.factors.diagonals();
+        for b in right.chunks_mut(n) {
+            for j in 0..n {
+                let mut value = b[j];
+                for i in max_difference!(0, j, kd)..j {
+                    value = value - values[j * diagonals + kd + i - j] * b[i];
+                }
+                b[j] = value / values[j * diagonals + kd];
+            }
+            for j in (0..n).rev() {
+                let mut value = b[j];
+                for i in (j + 1)..min!(n, j + kl + 1) {
+                    value = value - values[j * diagonals + kd + i - j] * b[i];
+                }
+                b[j] = value;
+                let l = self.pivots[j];
+                if l != j {
+                    b.swap(l, j);
+                }
+            }
+        }
+    }
+}
+
+fn factorize<T>(factors: &mut Banded<T>, pivots: &mut [usize]) -> Result<()>
+where
+    T: Element + Float,
+{
+    let &mut Banded {
+        rows: n,
+        superdiagonals: kv,
+        subdiagonals: kl,
+        ref mut values,
+        ..
+    } = factors;
+    let diagonals = kv + 1 + kl;
+    let ku = kv - kl;
+    macro_rules! at(($i:expr, $j:expr) => ({
+        let (i, j) = ($i, $j);
+        j * diagonals + kv + i - j
+    }));
+    let mut last = 0;
+    for j in 0..n {
+        let count = min!(kl, n - 1 - j);
+        let mut p = j;
+        for i in (j + 1)..(j + count + 1) {
+            if values[at!(i, j)].abs() > values[at!(p, j)].abs() {
+                p = i;
+            }
+        }
+        pivots[j] = p;
+        let pivot = values[at!(p, j)];
+        if Element::is_zero(&pivot) {
+            raise!(format!("the matrix is singular (column {})", j));
+        }
+        last = max!(last, min!(p + ku, n - 1));
+        if p != j {
+            for k in j..(last + 1) {
+                values.swap(at!(p, k), at!(j, k));
+            }
+        }
+        for i in (j + 1)..(j + count + 1) {
+            values[at!(i, j)] = values[at!(i, j)] / pivot;
+        }
+        for k in (j + 1)..(last + 1) {
+            let value = values[at!(j, k)];
+            if Element::is_zero(&value) {
+                continue;
+            }
+            for i in (j + 1)..(j + count + 1) {
+                values[at!(i, k)] = values[at!(i, k)] - values[at!(i, j)] * value;
+            }
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use assert;
+    use prelude::*;
+
+    fn multiply(matrix: &Banded<f64>, vector: &[f64]) -> Vec<f64> {
+        let mut result = vec![0.0; matrix.rows];
+        for (i, j, &value) in matrix.iter() {
+            result[i] += value * vector[j];
+        }
+        result
+    }
+
+    #[test]
+    fn lu() {
+        let matrix = new!(
+            5,
+            5,
+            1,
+            2,
+            matrix![
+                0.0, 2.0, 1.0, 1.0, 3.0;
+                1.0, 1.0, 4.0, 2.0, 1.0;
+                3.0, 5.0, 1.0, 1.0, 0.0;
+                4.0, 1.0, 2.0, 0.0, 0.0;
+            ]
+        );
+        let lu = matrix.lu().unwrap();
+        assert_eq!((lu.factors.superdiagonals, lu.factors.subdiagonals), (3, 2));
+        assert_eq!(&lu.pivots, &[2, 1, 4, 3, 4]);
+        assert::close(&[lu.determinant()], &[-7.0], 1e-12);
+    }
+
+    #[test]
+    fn lu_singular() {
+        let matrix = new!(2, 2, 0, 1, vec![1.0, 1.0, 0.0, 0.0]);
+        assert!(matrix.lu().is_err());
+        assert!(Banded::<f64>::new((2, 3), 1, 1).lu().is_err());
+    }
+
+    #[test]
+    fn solve() {
+        let matrix = new!(
+            5,
+            5,
+            1,
+            2,
+            matrix![
+                0.0, 2.0, 1.0, 1.0, 3.0;
+                1.0, 1.0, 4.0, 2.0, 1.0;
+                3.0, 5.0, 1.0, 1.0, 0.0;
+                4.0, 1.0, 2.0, 0.0, 0.0;
+            ]
+        );
+        let lu = matrix.lu().unwrap();
+        let expected = vec![1.0, -2.0, 3.0, -4.0, 5.0, 2.0, 1.0, 0.0, -1.0, 3.0];
+        let mut right = multiply(&matrix, &expected[..5]);
+        right.extend(multiply(&matrix, &expected[5..]));
+        lu.solve(&mut right);
+        assert::close(&right, &expected, 1e-13);
+    }
+
+    #[test]
+    fn solve_transpose() {
+        let matrix = new!(
+            5,
+            5,
+            1,
+            2,
+            matrix![
+                0.0, 2.0, 1.0, 1.0, 3.0;
+                1.0, 1.0, 4.0, 2.0, 1.0;
+                3.0, 5.0, 1.0, 1.0, 0.0;
+                4.0, 1.0, 2.0, 0.0, 0.0;
+            ]
+        );
+        let lu = matrix.lu().unwrap();
+        let expected = vec![1.0, -2.0, 3.0, -4.0, 5.0];
+        let mut right = multiply(&matrix.transpose(), &expected);
+        lu.solve_transpose(&mut right);
+        assert::close(&right, &expected, 1e-13);
+    }
+}
@@ -2,14 +2,18 @@
 //!
 //! The format is suitable for matrices with a small number of superdiagonals
 //! and/or subdiagonals relative to the smallest dimension. The format is
-//! compatible with the [one][1] adopted by [LAPACK][2].
+//! compatible with the [one][1] adopted by [LAPACK][2], and the LU
+//! decomposition is available via `Banded::lu`. Symmetric matrices can be
+//! stored by their upper or lower band only via `Symmetric`, which provides
+//! the Cholesky decomposition via `Symmetric::cholesky`.
 //!
 //! [1]: http://www.netlib.org/lapack/lug/node124.html
 //! [2]: http://www.netlib.org/lapack
 
-use std::iter;
+use std::{iter, slice};
 
-use {Element, Matrix, Size};
+use format::{product, sum};
+use {Element, Matrix, Position, Result, Size};
 
 /// A banded matrix.
 #[derive(Clone, Debug, PartialEq)]
@@ -73,7 +77,13 @@ macro_rules! row_range(
 );
 
 mod convert;
+mod decomposition;
 mod operation;
+mod reorder;
+mod symmetric;
+
+pub use self::decomposition::{Cholesky, Lu};
+pub use self::symmetric::Symmetric;
 
 /// A sparse iterator.
 pub struct Iterator<'l, T: 'l + Element> {
@@ -83,6 +93,17 @@ pub struct Iterator<'l, T: 'l + Element> {
     finish: usize,
 }
 
+/// A sparse iterator allowing mutation.
+pub struct IteratorMut<'l, T: 'l + Element> {
+    rows: usize,
+    superdiagonals: usize,
+    subdiagonals: usize,
+    columns: iter::Enumerate<slice::ChunksMut<'l, T>>,
+    column: usize,
+    row: usize,
+    values: slice::IterMut<'l, T>,
+}
+
 #[cfg(debug_assertions)]
 impl<T: Element> ::format::Validate for Banded<T> {
     fn validate(&self) {
@@ -100,17 +121,123 @@ impl<T: Element> Banded<T> {
         new!(rows, columns, superdiagonals, subdiagonals, values)
     }
 
+    /// Create a matrix from its parts checking that the values form a
+    /// `(superdiagonals + 1 + subdiagonals) × columns` matrix.
+    pub fn from_parts<S: Size>(
+        size: S,
+        superdiagonals: usize,
+        subdiagonals: usize,
+        values: Vec<T>,
+    ) -> Result<Self> {
+        let (rows, columns) = size.dimensions();
+        let diagonals = sum(sum(superdiagonals, 1)?, subdiagonals)?;
+        let expected = product(diagonals, columns)?;
+        if values.len() != expected {
+            raise!(format!(
+                "a banded matrix with {} diagonals and {} columns requires {} values \
+                 but {} have been given",
+                diagonals,
+                columns,
+                expected,
+                values.len()
+            ));
+        }
+        Ok(new!(rows, columns, superdiagonals, subdiagonals, values))
+    }
+
     /// Return the number of diagonals.
     #[inline]
     pub fn diagonals(&self) -> usize {
         self.superdiagonals + 1 + self.subdiagonals
     }
 
+    /// Read an element.
+    pub fn get<P: Position>(&self, position: P) -> T {
+        let (i, j) = position.coordinates();
+        debug_assert!(i < self.rows && j < self.columns);
+        match self.position(i, j) {
+            Some(k) => self.values[k],
+            _ => T::zero(),
+        }
+    }
+
+    /// Assign a value to an element.
+    ///
+    /// The function panics when the position lies outside the band.
+    pub fn set<P: Position>(&mut self, position: P, value: T) {
+        let (i, j) = position.coordinates();
+        debug_assert!(i < self.rows && j < self.columns);
+        match self.position(i, j) {
+            Some(k) => self.values[k] = value,
+            _ => panic!("the position ({}, {}) lies outside the band", i, j),
+        }
+    }
+
     /// Return a sparse iterator.
     #[inline]
     pub fn iter<'l>(&'l self) -> Iterator<'l, T> {
         Iterator::new(self)
     }
+
+    /// Return a sparse iterator allowing mutation.
+    #[inline]
+    pub fn iter_mut<'l>(&'l mut self) -> IteratorMut<'l, T> {
+        IteratorMut::new(self)
+    }
+
+    /// Change the numbers of superdiagonals and subdiagonals.
+    ///
+    /// The elements falling outside the new band are discarded, and the ones
+    /// added to it are zero.
+    pub fn resize(&mut self, superdiagonals: usize, subdiagonals: usize) {
+        let current = self.subdiagonals;
+        self.rebase(superdiagonals, current);
+        self.rebase(superdiagonals, subdiagonals);
+    }
+
+    fn position(&self, i: usize, j: usize) -> Option<usize> {
+        if i + self.superdiagonals < j || j + self.subdiagonals < i {
+            None
+        } else {
+            Some(j * self.diagonals() + self.superdiagonals + i - j)
+        }
+    }
+
+    // The numbers of diagonals and superdiagonals should change in the same
+    // direction, which makes it possible to move the elements in place.
+    fn rebase(&mut self, superdiagonals: usize, subdiagonals: usize) {
+        let (from, into) = (self.diagonals(), superdiagonals + 1 + subdiagonals);
+        let (current, columns) = (self.superdiagonals, self.columns);
+        let growing = into > from || superdiagonals > current;
+        if growing {
+            self.values.resize(into * columns, T::zero());
+        }
+        let values = &mut self.values;
+        let mut relocate = |j: usize, k: usize| {
+            let l = k + current;
+            values[j * into + k] = if l >= superdiagonals && l - superdiagonals < from {
+                values[j * from + l - superdiagonals]
+            } else {
+                T::zero()
+            };
+        };
+        if growing {
+            for j in (0..columns).rev() {
+                for k in (0..into).rev() {
+                    relocate(j, k);
+                }
+            }
+        } else {
+            for j in 0..columns {
+                for k in 0..into {
+                    relocate(j, k);
+                }
+            }
+        }
+        self.values.truncate(into * columns);
+        self.superdiagonals = superdiagonals;
+        self.subdiagonals = subdiagonals;
+    }
 }
 
 impl<T: Element> Matrix for Banded<T> {
@@ -140,6 +267,41 @@ impl<'l, T: Element> Iterator<'l, T> {
     }
 }
 
+impl<'l, T: Element> IteratorMut<'l, T> {
+    fn new(matrix: &'l mut Banded<T>) -> IteratorMut<'l, T> {
+        let diagonals = matrix.diagonals();
+        IteratorMut {
+            rows: matrix.rows,
+            superdiagonals: matrix.superdiagonals,
+            subdiagonals: matrix.subdiagonals,
+            columns: matrix.values.chunks_mut(diagonals).enumerate(),
+            column: 0,
+            row: 0,
+            values: [].iter_mut(),
+        }
+    }
+}
+
+impl<'l, T: Element> iter::Iterator for IteratorMut<'l, T> {
+    type Item = (usize, usize, &'l mut T);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(value) = self.values.next() {
+                self.row += 1;
+                return Some((self.row - 1, self.column, value));
+            }
+            let (j, values) = self.columns.next()?;
+            let start = row_start!(self.rows, self.superdiagonals, j);
+            let finish = max!(start, row_finish!(self.rows, self.subdiagonals, j));
+            let offset = self.superdiagonals + start - j;
+            self.column = j;
+            self.row = start;
+            self.values = values[offset..(offset + finish - start)].iter_mut();
+        }
+    }
+}
+
 impl<'l, T: Element> iter::Iterator for Iterator<'l, T> {
     type Item = (usize, usize, &'l T);
 
@@ -188,6 +350,112 @@ mod tests {
         assert_eq!(matrix.nonzeros(), 17 - 2);
     }
 
+    #[test]
+    fn from_parts() {
+        let matrix = Banded::from_parts((3, 2), 1, 0, vec![0.0, 1.0, 2.0, 3.0]).unwrap();
+        assert_eq!(matrix, new!(3, 2, 1, 0, vec![0.0, 1.0, 2.0, 3.0]));
+        assert!(Banded::from_parts((3, 2), 1, 1, vec![0.0, 1.0, 2.0, 3.0]).is_err());
+        assert!(Banded::<f64>::from_parts((3, 2), usize::max_value(), 0, vec![]).is_err());
+        assert!(Banded::<f64>::from_parts((3, usize::max_value()), 1, 0, vec![]).is_err());
+    }
+
+    #[test]
+    fn get() {
+        let matrix = new!(
+            4,
+            3,
+            1,
+            2,
+            matrix![
+                0.0, 4.0, 7.0;
+                1.0, 5.0, 8.0;
+                2.0, 6.0, 9.0;
+                3.0, 0.0, 0.0;
+            ]
+        );
+        assert_eq!(matrix.get((0, 0)), 1.0);
+        assert_eq!(matrix.get((0, 1)), 4.0);
+        assert_eq!(matrix.get((2, 0)), 3.0);
+        assert_eq!(matrix.get((0, 2)), 0.0);
+        assert_eq!(matrix.get((3, 2)), 9.0);
+        assert_eq!(matrix.get((3, 0)), 0.0);
+    }
+
+    #[test]
+    fn set() {
+        let mut matrix = Banded::new((3, 4), 1, 1);
+        matrix.set((0, 1), 1.0);
+        matrix.set((2, 1), 2.0);
+        matrix.set((2, 3), 3.0);
+        assert_eq!(
+            &*Conventional::from(matrix),
+            &*matrix![
+                0.0, 1.0, 0.0, 0.0;
+                0.0, 0.0, 0.0, 0.0;
+                0.0, 2.0, 0.0, 3.0;
+            ]
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn set_outside() {
+        let mut matrix = Banded::new(3, 1, 0);
+        matrix.set((1, 0), 1.0);
+    }
+
+    #[test]
+    fn iter_mut() {
+        let mut matrix = Banded::new((4, 3), 1, 1);
+        for (i, j, value) in matrix.iter_mut() {
+            *value = (10 * i + j) as f64;
+        }
+        assert_eq!(
+            &*Conventional::from(&matrix),
+            &*matrix![
+                 0.0,  1.0,  0.0;
+                10.0, 11.0, 12.0;
+                 0.0, 21.0, 22.0;
+                 0.0,  0.0, 32.0;
+            ]
+        );
+        let mut matrix = Banded::<f64>::new((2, 4), 1, 0);
+        assert_eq!(matrix.iter_mut().count(), matrix.iter().count());
+    }
+
+    #[test]
+    fn resize() {
+        let matrix = new!(
+            4,
+            3,
+            1,
+            2,
+            matrix![
+                0.0, 4.0, 7.0;
+                1.0, 5.0, 8.0;
+                2.0, 6.0, 9.0;
+                3.0, 0.0, 0.0;
+            ]
+        );
+        let conventional = Conventional::from(&matrix);
+        for &(superdiagonals, subdiagonals) in &[(3, 3), (0, 4), (2, 0), (0, 0), (1, 2)] {
+            let mut matrix = matrix.clone();
+            matrix.resize(superdiagonals, subdiagonals);
+            assert_eq!(
+                (matrix.superdiagonals, matrix.subdiagonals),
+                (superdiagonals, subdiagonals)
+            );
+            assert_eq!(matrix.values.len(), matrix.diagonals() * 3);
+            for i in 0..4 {
+                for j in 0..3 {
+                    let inside = i + superdiagonals >= j && j + subdiagonals >= i;
+                    let expected = if inside { conventional[(i, j)] } else { 0.0 };
+                    assert_eq!(matrix.get((i, j)), expected);
+                }
+            }
+        }
+    }
+
     #[test]
     fn iter_tall() {
         let matrix = new!(
@@ -1,6 +1,133 @@
-use format::Banded;
-use operation::Transpose;
-use Element;
+use format::multiply::Columns;
+use format::{Banded, Conventional, Diagonal};
+use operation::{Multiply, MultiplyInto, Transpose};
+use {Element, Number};
+
+impl<T> Multiply<Banded<T>, Banded<T>> for Banded<T>
+where
+    T: Element + Number,
+{
+    fn multiply(&self, right: &Banded<T>) -> Banded<T> {
+        debug_assert_eq!(self.columns, right.rows);
+        let mut result = Banded::new(
+            (self.rows, right.columns),
+            self.superdiagonals + right.superdiagonals,
+            self.subdiagonals + right.subdiagonals,
+        );
+        let (left_diagonals, right_diagonals) = (self.diagonals(), right.diagonals());
+        let diagonals = result.diagonals();
+        for j in 0..right.columns {
+            for l in row_range!(right.rows, right.superdiagonals, right.subdiagonals, j) {
+                let factor = right.values[j * right_diagonals + right.superdiagonals + l - j];
+                for i in row_range!(self.rows, self.superdiagonals, self.subdiagonals, l) {
+                    let value = self.values[l * left_diagonals + self.superdiagonals + i - l];
+                    let k = j * diagonals + result.superdiagonals + i - j;
+                    result.values[k] = result.values[k] + value * factor;
+                }
+            }
+        }
+        result
+    }
+}
+
+impl<T> Multiply<Conventional<T>, Conventional<T>> for Banded<T>
+where
+    T: Element + Number,
+{
+    fn multiply(&self, right: &Conventional<T>) -> Conventional<T> {
+        debug_assert_eq!(self.columns, right.rows);
+        let mut result = Conventional::new((self.rows, right.columns));
+        self.multiply_into(&right.values as &[T], &mut result.values);
+        result
+    }
+}
+
+impl<T> Multiply<Diagonal<T>, Banded<T>> for Banded<T>
+where
+    T: Element + Number,
+{
+    fn multiply(&self, right: &Diagonal<T>) -> Banded<T> {
+        debug_assert_eq!(self.columns, right.rows);
+        let mut result = Banded::new(
+            (self.rows, right.columns),
+            self.superdiagonals,
+            self.subdiagonals,
+        );
+        for (i, j, &value) in self.iter() {
+            if j < right.values.len() {
+                result.set((i, j), value * right.values[j]);
+            }
+        }
+        result
+    }
+}
+
+impl<T> Multiply<Banded<T>, Banded<T>> for Diagonal<T>
+where
+    T: Element + Number,
+{
+    fn multiply(&self, right: &Banded<T>) -> Banded<T> {
+        debug_assert_eq!(self.columns, right.rows);
+        let mut result = Banded::new(
+            (self.rows, right.columns),
+            right.superdiagonals,
+            right.subdiagonals,
+        );
+        for (i, j, &value) in right.iter() {
+            if i < self.values.len() {
+                result.set((i, j), self.values[i] * value);
+            }
+        }
+        result
+    }
+}
+
+impl<T> Multiply<Banded<T>, Conventional<T>> for Conventional<T>
+where
+    T: Element + Number,
+{
+    fn multiply(&self, right: &Banded<T>) -> Conventional<T> {
+        let (m, p, n) = (self.rows, self.columns, right.columns);
+        debug_assert_eq!(p, right.rows);
+        let mut result = Conventional::new((m, n));
+        multiply_matrix_right(&self.values, right, &mut result.values, m, p, n);
+        result
+    }
+}
+
+impl<T> MultiplyInto<[T], [T]> for Banded<T>
+where
+    T: Element + Number,
+{
+    #[inline]
+    fn multiply_into(&self, right: &[T], result: &mut [T]) {
+        let (m, p) = (self.rows, self.columns);
+        let n = right.len() / p;
+        multiply_matrix_left(self, right, result, m, p, n)
+    }
+}
+
+impl<T> MultiplyInto<Vec<T>, [T]> for Banded<T>
+where
+    T: Element + Number,
+{
+    #[inline(always)]
+    fn multiply_into(&self, right: &Vec<T>, result: &mut [T]) {
+        MultiplyInto::multiply_into(self, right as &[T], result)
+    }
+}
+
+impl<T: Element> Columns<T> for Banded<T> {
+    fn column<F>(&self, j: usize, mut callback: F)
+    where
+        F: FnMut(usize, T),
+    {
+        let diagonals = self.diagonals();
+        for i in row_range!(self.rows, self.superdiagonals, self.subdiagonals, j) {
+            callback(i, self.values[j * diagonals + self.superdiagonals + i - j]);
+        }
+    }
+}
 
 impl<T: Element> Transpose for Banded<T> {
     fn transpose(&self) -> Self {
@@ -24,10 +151,176 @@ impl<T: Element> Transpose for Banded<T> {
     }
 }
 
+fn multiply_matrix_left<T>(a: &Banded<T>, b: &[T], c: &mut [T], m: usize, p: usize, n: usize)
+where
+    T: Element + Number,
+{
+    debug_assert_eq!(a.rows * a.columns, m * p);
+    debug_assert_eq!(b.len(), p * n);
+    debug_assert_eq!(c.len(), m * n);
+    let &Banded {
+        superdiagonals,
+        subdiagonals,
+        ref values,
+        ..
+    } = a;
+    let diagonals = a.diagonals();
+    for k in 0..n {
+        let (bo, co) = (k * p, k * m);
+        for j in 0..p {
+            let factor = b[bo + j];
+            for i in row_range!(m, superdiagonals, subdiagonals, j) {
+                let value = values[j * diagonals + superdiagonals + i - j];
+                c[co + i] = c[co + i] + value * factor;
+            }
+        }
+    }
+}
+
+fn multiply_matrix_right<T>(a: &[T], b: &Banded<T>, c: &mut [T], m: usize, p: usize, n: usize)
+where
+    T: Element + Number,
+{
+    debug_assert_eq!(a.len(), m * p);
+    debug_assert_eq!(b.rows * b.columns, p * n);
+    debug_assert_eq!(c.len(), m * n);
+    let &Banded {
+        superdiagonals,
+        subdiagonals,
+        ref values,
+        ..
+    } = b;
+    let diagonals = b.diagonals();
+    for j in 0..n {
+        let co = j * m;
+        for l in row_range!(p, superdiagonals, subdiagonals, j) {
+            let (factor, ao) = (values[j * diagonals + superdiagonals + l - j], l * m);
+            for i in 0..m {
+                c[co + i] = c[co + i] + a[ao + i] * factor;
+            }
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use prelude::*;
 
+    #[test]
+    fn multiply_banded() {
+        let left = new!(
+            4,
+            3,
+            1,
+            2,
+            matrix![
+                0.0, 4.0, 7.0;
+                1.0, 5.0, 8.0;
+                2.0, 6.0, 9.0;
+                3.0, 0.0, 0.0;
+            ]
+        );
+        let right = new!(
+            3,
+            5,
+            2,
+            0,
+            matrix![
+                0.0, 0.0, 3.0, 5.0, 7.0;
+                0.0, 2.0, 4.0, 6.0, 0.0;
+                1.0, 1.0, 1.0, 0.0, 0.0;
+            ]
+        );
+        let result = left.multiply(&right);
+        assert_eq!((result.superdiagonals, result.subdiagonals), (3, 2));
+        assert_eq!(
+            Conventional::from(result),
+            Conventional::from(left).multiply(&right)
+        );
+    }
+
+    #[test]
+    fn multiply_conventional_left() {
+        let left = new!(
+            4,
+            3,
+            1,
+            2,
+            matrix![
+                0.0, 4.0, 7.0;
+                1.0, 5.0, 8.0;
+                2.0, 6.0, 9.0;
+                3.0, 0.0, 0.0;
+            ]
+        );
+        let right = Conventional::from_vec(
+            (3, 2),
+            matrix![
+                1.0, 4.0;
+                2.0, 5.0;
+                3.0, 6.0;
+            ],
+        );
+        assert_eq!(
+            &*left.multiply(&right),
+            &*matrix![
+                 9.0, 24.0;
+                33.0, 75.0;
+                39.0, 90.0;
+                27.0, 54.0;
+            ]
+        );
+    }
+
+    #[test]
+    fn multiply_conventional_right() {
+        let left = Conventional::from_vec(
+            (2, 4),
+            matrix![
+                1.0, 2.0, 3.0, 4.0;
+                4.0, 3.0, 2.0, 1.0;
+            ],
+        );
+        let right = new!(
+            4,
+            3,
+            1,
+            2,
+            matrix![
+                0.0, 4.0, 7.0;
+                1.0, 5.0, 8.0;
+                2.0, 6.0, 9.0;
+                3.0, 0.0, 0.0;
+            ]
+        );
+        assert_eq!(
+            &*left.multiply(&right),
+            &*matrix![
+                14.0, 32.0, 74.0;
+                16.0, 43.0, 46.0;
+            ]
+        );
+    }
+
+    #[test]
+    fn multiply_into() {
+        let matrix = new!(
+            4,
+            3,
+            1,
+            2,
+            matrix![
+                0.0, 4.0, 7.0;
+                1.0, 5.0, 8.0;
+                2.0, 6.0, 9.0;
+                3.0, 0.0, 0.0;
+            ]
+        );
+        let mut result = vec![1.0; 4];
+        matrix.multiply_into(&[1.0, 2.0, 3.0][..], &mut result);
+        assert_eq!(&result, &[10.0, 34.0, 40.0, 28.0]);
+    }
+
     #[test]
     fn transpose() {
         let matrix = new!(
@@ -0,0 +1,113 @@
+use std::collections::VecDeque;
+
+use format::{Banded, Compressed};
+use {Element, Result};
+
+impl<T: Element> Banded<T> {
+    /// Convert a square compressed matrix into a banded one after reordering
+    /// its rows and columns via the reverse Cuthill–McKee algorithm.
+    ///
+    /// The algorithm is applied to the sparsity pattern of `A + Aᵀ`. The
+    /// function returns the reordered matrix `B` along with the permutation
+    /// `p` such that `B[(k, l)] = A[(p[k], p[l])]`.
+    pub fn from_compressed_reordered(matrix: &Compressed<T>) -> Result<(Self, Vec<usize>)> {
+        let size = matrix.rows;
+        if size != matrix.columns {
+            raise!(format!(
+                "the matrix should be square but it is {} × {}",
+                size, matrix.columns
+            ));
+        }
+        let mut neighbors = vec![vec![]; size];
+        for (i, j, _) in matrix.iter() {
+            if i != j {
+                neighbors[i].push(j);
+                neighbors[j].push(i);
+            }
+        }
+        for list in &mut neighbors {
+            list.sort();
+            list.dedup();
+        }
+        let permutation = reverse_cuthill_mckee(&neighbors);
+        let mut positions = vec![0; size];
+        for (k, &i) in permutation.iter().enumerate() {
+            positions[i] = k;
+        }
+        let result = super::convert::from_triplets(size, size, || {
+            matrix
+                .iter()
+                .map(|(i, j, &value)| (positions[i], positions[j], value))
+        });
+        Ok((result, permutation))
+    }
+}
+
+fn reverse_cuthill_mckee(neighbors: &[Vec<usize>]) -> Vec<usize> {
+    let size = neighbors.len();
+    let mut order = Vec::with_capacity(size);
+    let mut visited = vec![false; size];
+    let mut queue = VecDeque::new();
+    while order.len() < size {
+        let root = (0..size)
+            .filter(|&i| !visited[i])
+            .min_by_key(|&i| neighbors[i].len())
+            .unwrap();
+        visited[root] = true;
+        queue.push_back(root);
+        while let Some(i) = queue.pop_front() {
+            order.push(i);
+            let mut next = neighbors[i]
+                .iter()
+                .cloned()
+                .filter(|&j| !visited[j])
+                .collect::<Vec<_>>();
+            next.sort_by_key(|&j| neighbors[j].len());
+            for j in next {
+                visited[j] = true;
+                queue.push_back(j);
+            }
+        }
+    }
+    order.reverse();
+    order
+}
+
+#[cfg(test)]
+mod tests {
+    use format::compressed::Variant;
+    use prelude::*;
+
+    #[test]
+    fn from_compressed_reordered() {
+        let mut matrix = Compressed::new(6, Variant::Column);
+        for i in 0..6 {
+            matrix.set((i, i), 1.0 + i as f64);
+        }
+        for &(i, j) in &[(0, 3), (3, 5), (5, 1), (1, 4), (4, 2)] {
+            matrix.set((i, j), -1.0);
+            matrix.set((j, i), -2.0);
+        }
+        assert_eq!(Banded::from(&matrix).diagonals(), 9);
+
+        let (banded, permutation) = Banded::from_compressed_reordered(&matrix).unwrap();
+        assert_eq!((banded.superdiagonals, banded.subdiagonals), (1, 1));
+        let mut sorted = permutation.clone();
+        sorted.sort();
+        assert_eq!(sorted, (0..6).collect::<Vec<_>>());
+        for k in 0..6 {
+            for l in 0..6 {
+                assert_eq!(
+                    Conventional::from(&banded)[(k, l)],
+                    matrix.get((permutation[k], permutation[l]))
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn from_compressed_reordered_rectangular() {
+        let matrix = Compressed::<f64>::new((2, 3), Variant::Column);
+        assert!(Banded::from_compressed_reordered(&matrix).is_err());
+    }
+}
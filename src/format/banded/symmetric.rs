@@ -0,0 +1,279 @@
+use format::packed::Variant;
+use format::{product, sum, Banded, Conventional};
+use {Element, Matrix, Position, Result, Size};
+
+/// A symmetric banded matrix.
+///
+/// Only the upper or lower band is stored, which is compatible with the
+/// [layout][1] adopted by `pbtrf` and related routines in LAPACK.
+///
+/// [1]: http://www.netlib.org/lapack/lug/node124.html
+#[derive(Clone, Debug, PartialEq)]
+pub struct Symmetric<T: Element> {
+    /// The number of rows or columns.
+    pub size: usize,
+    /// The number of superdiagonals, which is equal to the number of
+    /// subdiagonals.
+    pub offdiagonals: usize,
+    /// The format variant.
+    pub variant: Variant,
+    /// The values of the stored band stored as a `(offdiagonals + 1) × size`
+    /// matrix. When `variant = Upper`, the first row corresponds to the
+    /// uppermost superdiagonal and the last row to the diagonal. When `variant
+    /// = Lower`, the first row corresponds to the diagonal and the last row to
+    /// the lowest subdiagonal.
+    pub values: Vec<T>,
+}
+
+#[cfg(debug_assertions)]
+impl<T: Element> ::format::Validate for Symmetric<T> {
+    fn validate(&self) {
+        assert_eq!(self.values.len(), (self.offdiagonals + 1) * self.size);
+    }
+}
+
+size!(Symmetric, size, size);
+
+impl<T: Element> Symmetric<T> {
+    /// Create a zero matrix.
+    pub fn new<S: Size>(size: S, offdiagonals: usize, variant: Variant) -> Self {
+        let (rows, _columns) = size.dimensions();
+        debug_assert!(rows == _columns);
+        Symmetric {
+            size: rows,
+            offdiagonals,
+            variant,
+            values: vec![T::zero(); (offdiagonals + 1) * rows],
+        }
+    }
+
+    /// Create a matrix from its parts checking that the size is square and
+    /// that the values form a `(offdiagonals + 1) × size` matrix.
+    pub fn from_parts<S: Size>(
+        size: S,
+        offdiagonals: usize,
+        variant: Variant,
+        values: Vec<T>,
+    ) -> Result<Self> {
+        let (rows, columns) = size.dimensions();
+        if rows != columns {
+            raise!(format!(
+                "a symmetric banded matrix should be square but it is {} × {}",
+                rows, columns
+            ));
+        }
+        let expected = product(sum(offdiagonals, 1)?, rows)?;
+        if values.len() != expected {
+            raise!(format!(
+                "a symmetric banded matrix with {} off-diagonals and {} columns \
+                 requires {} values but {} have been given",
+                offdiagonals,
+                rows,
+                expected,
+                values.len()
+            ));
+        }
+        Ok(Symmetric {
+            size: rows,
+            offdiagonals,
+            variant,
+            values,
+        })
+    }
+
+    /// Create a matrix from the upper band when `variant = Upper` or the lower
+    /// band when `variant = Lower` of a square banded matrix.
+    pub fn from_banded(matrix: &Banded<T>, variant: Variant) -> Result<Self> {
+        let &Banded {
+            rows,
+            columns,
+            superdiagonals,
+            subdiagonals,
+            ref values,
+        } = validate!(matrix);
+        if rows != columns {
+            raise!(format!(
+                "a symmetric banded matrix should be square but it is {} × {}",
+                rows, columns
+            ));
+        }
+        let offdiagonals = match variant {
+            Variant::Lower => subdiagonals,
+            Variant::Upper => superdiagonals,
+        };
+        let diagonals = matrix.diagonals();
+        let mut result = Symmetric::new(rows, offdiagonals, variant);
+        for j in 0..columns {
+            for i in row_range!(rows, superdiagonals, subdiagonals, j) {
+                let (k, l) = match variant {
+                    Variant::Lower if i >= j => (j, i),
+                    Variant::Upper if i <= j => (i, j),
+                    _ => continue,
+                };
+                let position = result.position(k, l);
+                result.values[position] = values[j * diagonals + superdiagonals + i - j];
+            }
+        }
+        Ok(result)
+    }
+
+    /// Read an element.
+    pub fn get<P: Position>(&self, position: P) -> T {
+        let (i, j) = position.coordinates();
+        let (i, j) = if i > j { (j, i) } else { (i, j) };
+        if j - i > self.offdiagonals {
+            T::zero()
+        } else {
+            self.values[self.position(i, j)]
+        }
+    }
+
+    /// Return the index of the stored element corresponding to the `(i, j)`th
+    /// element of the upper triangle or the `(j, i)`th element of the lower
+    /// one, where `i ≤ j ≤ i + offdiagonals`.
+    #[inline(always)]
+    pub(super) fn position(&self, i: usize, j: usize) -> usize {
+        let diagonals = self.offdiagonals + 1;
+        match self.variant {
+            Variant::Lower => i * diagonals + j - i,
+            Variant::Upper => j * diagonals + self.offdiagonals + i - j,
+        }
+    }
+}
+
+impl<T: Element> Matrix for Symmetric<T> {
+    type Element = T;
+
+    fn nonzeros(&self) -> usize {
+        let mut count = 0;
+        for j in 0..self.size {
+            for i in max_difference!(0, j, self.offdiagonals)..(j + 1) {
+                if !self.values[self.position(i, j)].is_zero() {
+                    count += if i == j { 1 } else { 2 };
+                }
+            }
+        }
+        count
+    }
+
+    #[inline]
+    fn zero<S: Size>(size: S) -> Self {
+        Symmetric::new(size, 0, Variant::Lower)
+    }
+}
+
+impl<'l, T: Element> From<&'l Symmetric<T>> for Banded<T> {
+    fn from(matrix: &'l Symmetric<T>) -> Self {
+        let &Symmetric {
+            size,
+            offdiagonals,
+            ref values,
+            ..
+        } = validate!(matrix);
+        let mut result = Banded::new(size, offdiagonals, offdiagonals);
+        let diagonals = result.diagonals();
+        for j in 0..size {
+            for i in max_difference!(0, j, offdiagonals)..(j + 1) {
+                let value = values[matrix.position(i, j)];
+                result.values[j * diagonals + offdiagonals + i - j] = value;
+                result.values[i * diagonals + offdiagonals + j - i] = value;
+            }
+        }
+        result
+    }
+}
+
+impl<T: Element> From<Symmetric<T>> for Banded<T> {
+    #[inline]
+    fn from(matrix: Symmetric<T>) -> Self {
+        (&matrix).into()
+    }
+}
+
+impl<'l, T: Element> From<&'l Symmetric<T>> for Conventional<T> {
+    #[inline]
+    fn from(matrix: &'l Symmetric<T>) -> Self {
+        Banded::from(matrix).into()
+    }
+}
+
+impl<T: Element> From<Symmetric<T>> for Conventional<T> {
+    #[inline]
+    fn from(matrix: Symmetric<T>) -> Self {
+        (&matrix).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use format::banded::Symmetric;
+    use format::packed::Variant;
+    use prelude::*;
+
+    #[test]
+    fn from_banded_lower() {
+        let matrix = new!(
+            4,
+            4,
+            2,
+            1,
+            matrix![
+                0.0, 0.0, 3.0, 5.0;
+                0.0, 2.0, 4.0, 6.0;
+                1.0, 2.0, 3.0, 4.0;
+                2.0, 4.0, 6.0, 0.0;
+            ]
+        );
+        let matrix = Symmetric::from_banded(&matrix, Variant::Lower).unwrap();
+        assert_eq!(matrix.offdiagonals, 1);
+        assert_eq!(&matrix.values, &[1.0, 2.0, 2.0, 4.0, 3.0, 6.0, 4.0, 0.0]);
+        assert_eq!(matrix.nonzeros(), 10);
+        assert_eq!(
+            &*Conventional::from(&matrix),
+            &*matrix![
+                1.0, 2.0, 0.0, 0.0;
+                2.0, 2.0, 4.0, 0.0;
+                0.0, 4.0, 3.0, 6.0;
+                0.0, 0.0, 6.0, 4.0;
+            ]
+        );
+    }
+
+    #[test]
+    fn from_banded_upper() {
+        let matrix = new!(
+            4,
+            4,
+            2,
+            1,
+            matrix![
+                0.0, 0.0, 3.0, 5.0;
+                0.0, 2.0, 4.0, 6.0;
+                1.0, 2.0, 3.0, 4.0;
+                2.0, 4.0, 6.0, 0.0;
+            ]
+        );
+        let matrix = Symmetric::from_banded(&matrix, Variant::Upper).unwrap();
+        assert_eq!(matrix.offdiagonals, 2);
+        assert_eq!((matrix.get((0, 2)), matrix.get((2, 0))), (3.0, 3.0));
+        assert_eq!((matrix.get((0, 3)), matrix.get((3, 1))), (0.0, 5.0));
+        assert_eq!(
+            &*Conventional::from(&matrix),
+            &*matrix![
+                1.0, 2.0, 3.0, 0.0;
+                2.0, 2.0, 4.0, 5.0;
+                3.0, 4.0, 3.0, 6.0;
+                0.0, 5.0, 6.0, 4.0;
+            ]
+        );
+        assert!(Symmetric::from_banded(&Banded::<f64>::new((2, 3), 1, 1), Variant::Upper).is_err());
+    }
+
+    #[test]
+    fn from_parts() {
+        let matrix = Symmetric::from_parts(2, 1, Variant::Upper, vec![0.0, 1.0, 2.0, 3.0]).unwrap();
+        assert_eq!(matrix.get((0, 1)), 2.0);
+        assert!(Symmetric::from_parts((2, 3), 1, Variant::Upper, vec![0.0; 6]).is_err());
+        assert!(Symmetric::from_parts(2, 1, Variant::Upper, vec![0.0; 3]).is_err());
+    }
+}
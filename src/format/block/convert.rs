@@ -0,0 +1,193 @@
+use format::compressed::Variant;
+use format::{Block, Compressed, Conventional};
+use Element;
+
+impl<'l, T: Element> From<&'l Block<T>> for Compressed<T> {
+    /// Convert into a compressed matrix in the compressed-row variant.
+    ///
+    /// The zero elements of the stored blocks are skipped.
+    fn from(matrix: &'l Block<T>) -> Self {
+        let &Block {
+            rows,
+            columns,
+            block_rows,
+            block_columns,
+            ref values,
+            ref indices,
+            ref offsets,
+            ..
+        } = validate!(matrix);
+        let block_size = matrix.block_size();
+        let mut result = Compressed::with_capacity((rows, columns), Variant::Row, values.len());
+        for i in 0..rows {
+            let (bi, ii) = (i / block_rows, i % block_rows);
+            for k in offsets[bi]..offsets[bi + 1] {
+                for jj in 0..block_columns {
+                    let value = values[k * block_size + jj * block_rows + ii];
+                    if !value.is_zero() {
+                        result.values.push(value);
+                        result.indices.push(indices[k] * block_columns + jj);
+                    }
+                }
+            }
+            result.offsets[i + 1] = result.values.len();
+        }
+        result.nonzeros = result.values.len();
+        result
+    }
+}
+
+impl<T: Element> From<Block<T>> for Compressed<T> {
+    #[inline]
+    fn from(matrix: Block<T>) -> Self {
+        (&matrix).into()
+    }
+}
+
+impl<'l, T: Element> From<&'l Block<T>> for Conventional<T> {
+    fn from(matrix: &'l Block<T>) -> Self {
+        let &Block {
+            rows,
+            columns,
+            block_rows,
+            block_columns,
+            ref values,
+            ref indices,
+            ref offsets,
+            ..
+        } = validate!(matrix);
+        let block_size = matrix.block_size();
+        let mut result = Conventional::new((rows, columns));
+        let mut bi = 0;
+        for (k, &bj) in indices.iter().enumerate() {
+            while offsets[bi + 1] <= k {
+                bi += 1;
+            }
+            let (i, j) = (bi * block_rows, bj * block_columns);
+            for jj in 0..block_columns {
+                let (from, into) = (k * block_size + jj * block_rows, (j + jj) * rows + i);
+                result.values[into..(into + block_rows)]
+                    .copy_from_slice(&values[from..(from + block_rows)]);
+            }
+        }
+        result
+    }
+}
+
+impl<T: Element> From<Block<T>> for Conventional<T> {
+    #[inline]
+    fn from(matrix: Block<T>) -> Self {
+        (&matrix).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use format::compressed::Variant;
+    use prelude::*;
+
+    #[test]
+    fn from_compressed() {
+        let matrix = Compressed::from(Conventional::from_vec(
+            (4, 6),
+            matrix![
+                1.0, 3.0, 0.0, 0.0, 0.0, 0.0;
+                2.0, 0.0, 0.0, 0.0, 0.0, 0.0;
+                0.0, 0.0, 0.0, 0.0, 5.0, 0.0;
+                0.0, 9.0, 0.0, 0.0, 6.0, 8.0;
+            ],
+        ));
+        let matrix = Block::from_compressed(&matrix, 2).unwrap();
+        assert_eq!(
+            matrix,
+            new!(
+                4,
+                6,
+                2,
+                2,
+                3,
+                vec![1.0, 2.0, 3.0, 0.0, 0.0, 0.0, 0.0, 9.0, 5.0, 6.0, 0.0, 8.0],
+                vec![0, 0, 2],
+                vec![0, 1, 3]
+            )
+        );
+        assert!(Block::from_compressed(&Compressed::<f64>::zero((4, 6)), (3, 2)).is_err());
+    }
+
+    #[test]
+    fn from_conventional_rectangular() {
+        let matrix = Conventional::from_vec(
+            (4, 6),
+            matrix![
+                1.0, 0.0, 0.0, 0.0, 0.0, 0.0;
+                0.0, 0.0, 2.0, 0.0, 0.0, 0.0;
+                0.0, 0.0, 0.0, 0.0, 0.0, 3.0;
+                0.0, 0.0, 0.0, 4.0, 0.0, 0.0;
+            ],
+        );
+        let matrix = Block::from_conventional(&matrix, (1, 3)).unwrap();
+        assert_eq!(
+            matrix,
+            new!(
+                4,
+                6,
+                1,
+                3,
+                4,
+                vec![1.0, 0.0, 0.0, 0.0, 0.0, 2.0, 0.0, 0.0, 3.0, 4.0, 0.0, 0.0],
+                vec![0, 0, 1, 1],
+                vec![0, 1, 2, 3, 4]
+            )
+        );
+    }
+
+    #[test]
+    fn into_compressed() {
+        let matrix = new!(
+            4,
+            6,
+            2,
+            2,
+            2,
+            vec![1.0, 2.0, 3.0, 0.0, 5.0, 6.0, 7.0, 8.0],
+            vec![0, 2],
+            vec![0, 1, 2]
+        );
+        let matrix = Compressed::from(matrix);
+        assert_eq!(
+            matrix,
+            Compressed::from_parts(
+                (4, 6),
+                Variant::Row,
+                vec![1.0, 3.0, 2.0, 5.0, 7.0, 6.0, 8.0],
+                vec![0, 1, 0, 4, 5, 4, 5],
+                vec![0, 2, 3, 5, 7],
+            )
+            .unwrap()
+        );
+    }
+
+    #[test]
+    fn into_conventional() {
+        let matrix = new!(
+            4,
+            6,
+            2,
+            2,
+            2,
+            vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0],
+            vec![0, 2],
+            vec![0, 1, 2]
+        );
+        let matrix = Conventional::from(matrix);
+        assert_eq!(
+            &*matrix,
+            &*matrix![
+                1.0, 3.0, 0.0, 0.0, 0.0, 0.0;
+                2.0, 4.0, 0.0, 0.0, 0.0, 0.0;
+                0.0, 0.0, 0.0, 0.0, 5.0, 7.0;
+                0.0, 0.0, 0.0, 0.0, 6.0, 8.0;
+            ]
+        );
+    }
+}
@@ -0,0 +1,359 @@
+//! The block format.
+//!
+//! The format is suitable for sparse matrices whose nonzero elements are
+//! clustered in small dense blocks of the same size. The format is the
+//! [block compressed-row][1] one: the matrix is partitioned into `block_rows
+//! × block_columns` blocks, and the nonzero blocks are stored by block rows.
+//!
+//! [1]: http://netlib.org/linalg/html_templates/node93.html
+
+use std::iter;
+
+use format::{product, Compressed, Conventional};
+use {Element, Matrix, Result, Size};
+
+/// A block matrix.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Block<T: Element> {
+    /// The number of rows.
+    pub rows: usize,
+    /// The number of columns.
+    pub columns: usize,
+    /// The number of rows in each block.
+    pub block_rows: usize,
+    /// The number of columns in each block.
+    pub block_columns: usize,
+    /// The number of stored blocks.
+    pub blocks: usize,
+    /// The values of the stored blocks such that the `k`th block occupies
+    /// `block_rows × block_columns` consecutive elements starting from
+    /// `values[k × block_rows × block_columns]` and is stored in the
+    /// column-major order.
+    pub values: Vec<T>,
+    /// The indices of block columns of the stored blocks.
+    pub indices: Vec<usize>,
+    /// The offsets of block rows such that the blocks of the `i`th block row
+    /// are those starting from the `j`th one where `j = offsets[i]`. The
+    /// vector has one additional element, which is always equal to `blocks`.
+    pub offsets: Vec<usize>,
+}
+
+macro_rules! new(
+    ($rows:expr, $columns:expr, $block_rows:expr, $block_columns:expr, $blocks:expr,
+     $values:expr, $indices:expr, $offsets:expr) => (
+        Block {
+            rows: $rows,
+            columns: $columns,
+            block_rows: $block_rows,
+            block_columns: $block_columns,
+            blocks: $blocks,
+            values: $values,
+            indices: $indices,
+            offsets: $offsets,
+        }
+    );
+);
+
+mod convert;
+mod operation;
+
+#[cfg(debug_assertions)]
+impl<T: Element> ::format::Validate for Block<T> {
+    fn validate(&self) {
+        assert_eq!(self.rows % self.block_rows, 0);
+        assert_eq!(self.columns % self.block_columns, 0);
+        assert_eq!(self.blocks * self.block_size(), self.values.len());
+        assert_eq!(self.blocks, self.indices.len());
+        assert_eq!(self.rows / self.block_rows + 1, self.offsets.len());
+    }
+}
+
+size!(Block);
+
+impl<T: Element> Block<T> {
+    /// Create a zero matrix.
+    ///
+    /// The dimensions of the matrix should be multiples of the dimensions of
+    /// a block.
+    pub fn new<S: Size, B: Size>(size: S, block: B) -> Self {
+        let (rows, columns) = size.dimensions();
+        let (block_rows, block_columns) = block.dimensions();
+        debug_assert_eq!(rows % block_rows, 0);
+        debug_assert_eq!(columns % block_columns, 0);
+        new!(
+            rows,
+            columns,
+            block_rows,
+            block_columns,
+            0,
+            vec![],
+            vec![],
+            vec![0; rows / block_rows + 1]
+        )
+    }
+
+    /// Create a zero matrix checking that the dimensions of a block are
+    /// positive and that the dimensions of the matrix are multiples of them.
+    pub fn try_new<S: Size, B: Size>(size: S, block: B) -> Result<Self> {
+        let (rows, columns) = size.dimensions();
+        let block = check(rows, columns, block.dimensions())?;
+        Ok(Block::new((rows, columns), block))
+    }
+
+    /// Create a matrix from its parts checking that they are consistent.
+    ///
+    /// The dimensions of the matrix should be multiples of the dimensions of
+    /// a block, and the block-column indices within each block row should be
+    /// strictly increasing.
+    pub fn from_parts<S: Size, B: Size>(
+        size: S,
+        block: B,
+        values: Vec<T>,
+        indices: Vec<usize>,
+        offsets: Vec<usize>,
+    ) -> Result<Self> {
+        let (rows, columns) = size.dimensions();
+        let (block_rows, block_columns) = check(rows, columns, block.dimensions())?;
+        let blocks = indices.len();
+        let expected = product(blocks, product(block_rows, block_columns)?)?;
+        if values.len() != expected {
+            raise!(format!(
+                "{} blocks of size {} × {} require {} values but {} have been given",
+                blocks,
+                block_rows,
+                block_columns,
+                expected,
+                values.len()
+            ));
+        }
+        let (major, minor) = (rows / block_rows, columns / block_columns);
+        if offsets.len() != major + 1 || offsets[0] != 0 || offsets[major] != blocks {
+            raise!(format!(
+                "there should be {} offsets starting with 0 and ending with {}",
+                major + 1,
+                blocks
+            ));
+        }
+        for l in 0..major {
+            let (start, finish) = (offsets[l], offsets[l + 1]);
+            if start > finish || finish > blocks {
+                raise!(format!("the offsets are not nondecreasing at {}", l));
+            }
+            for k in start..finish {
+                if indices[k] >= minor {
+                    raise!(format!(
+                        "the block index {} is out of bounds ({})",
+                        indices[k], minor
+                    ));
+                }
+                if k > start && indices[k - 1] >= indices[k] {
+                    raise!(format!(
+                        "the block indices are not strictly increasing at {}",
+                        k
+                    ));
+                }
+            }
+        }
+        Ok(new!(
+            rows,
+            columns,
+            block_rows,
+            block_columns,
+            blocks,
+            values,
+            indices,
+            offsets
+        ))
+    }
+
+    /// Create a matrix from a compressed one.
+    ///
+    /// All the blocks containing at least one stored element of the original
+    /// matrix are stored.
+    pub fn from_compressed<B: Size>(matrix: &Compressed<T>, block: B) -> Result<Self> {
+        let (rows, columns) = matrix.dimensions();
+        let block = check(rows, columns, block.dimensions())?;
+        Ok(from_triplets(
+            (rows, columns),
+            block,
+            matrix.iter().map(|(i, j, &value)| (i, j, value)),
+        ))
+    }
+
+    /// Create a matrix from a conventional one.
+    ///
+    /// All the blocks containing at least one nonzero element of the original
+    /// matrix are stored.
+    pub fn from_conventional<B: Size>(matrix: &Conventional<T>, block: B) -> Result<Self> {
+        let (rows, columns) = matrix.dimensions();
+        let block = check(rows, columns, block.dimensions())?;
+        Ok(from_triplets(
+            (rows, columns),
+            block,
+            matrix
+                .values
+                .iter()
+                .enumerate()
+                .filter(|&(_, value)| !value.is_zero())
+                .map(|(k, &value)| (k % rows, k / rows, value)),
+        ))
+    }
+
+    /// Return the number of elements in a block.
+    #[inline]
+    pub fn block_size(&self) -> usize {
+        self.block_rows * self.block_columns
+    }
+
+    /// Read an element.
+    pub fn get<P: ::Position>(&self, position: P) -> T {
+        let (i, j) = position.coordinates();
+        debug_assert!(i < self.rows && j < self.columns);
+        let (bi, bj) = (i / self.block_rows, j / self.block_columns);
+        for k in self.offsets[bi]..self.offsets[bi + 1] {
+            if self.indices[k] == bj {
+                let l = (j % self.block_columns) * self.block_rows + i % self.block_rows;
+                return self.values[k * self.block_size() + l];
+            }
+            if self.indices[k] > bj {
+                break;
+            }
+        }
+        T::zero()
+    }
+}
+
+impl<T: Element> Matrix for Block<T> {
+    type Element = T;
+
+    fn nonzeros(&self) -> usize {
+        self.values
+            .iter()
+            .fold(0, |sum, &value| if value.is_zero() { sum } else { sum + 1 })
+    }
+
+    #[inline]
+    fn zero<S: Size>(size: S) -> Self {
+        Block::new(size, 1)
+    }
+}
+
+fn check(rows: usize, columns: usize, block: (usize, usize)) -> Result<(usize, usize)> {
+    let (block_rows, block_columns) = block;
+    if block_rows == 0 || block_columns == 0 {
+        raise!("the dimensions of a block should be positive");
+    }
+    if rows / block_rows * block_rows != rows || columns / block_columns * block_columns != columns
+    {
+        raise!(format!(
+            "a {} × {} matrix cannot be partitioned into {} × {} blocks",
+            rows, columns, block_rows, block_columns
+        ));
+    }
+    Ok(block)
+}
+
+fn from_triplets<T, I>(size: (usize, usize), block: (usize, usize), triplets: I) -> Block<T>
+where
+    T: Element,
+    I: iter::Iterator<Item = (usize, usize, T)>,
+{
+    let (rows, (block_rows, block_columns)) = (size.0, block);
+    let block_size = block_rows * block_columns;
+    let mut buckets = vec![vec![]; rows / block_rows];
+    for (i, j, value) in triplets {
+        buckets[i / block_rows].push((j / block_columns, i, j, value));
+    }
+    let mut matrix = Block::new(size, block);
+    for (bi, mut bucket) in buckets.into_iter().enumerate() {
+        bucket.sort_by_key(|&(bj, _, _, _)| bj);
+        for (bj, i, j, value) in bucket {
+            if matrix.indices[matrix.offsets[bi]..].last() != Some(&bj) {
+                matrix.indices.push(bj);
+                matrix.values.extend(vec![T::zero(); block_size]);
+                matrix.blocks += 1;
+            }
+            let k = (matrix.blocks - 1) * block_size;
+            matrix.values[k + (j % block_columns) * block_rows + i % block_rows] = value;
+        }
+        matrix.offsets[bi + 1] = matrix.blocks;
+    }
+    matrix
+}
+
+#[cfg(test)]
+mod tests {
+    use prelude::*;
+
+    #[test]
+    fn from_parts() {
+        let matrix = Block::from_parts(
+            (4, 6),
+            2,
+            vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0],
+            vec![0, 2],
+            vec![0, 1, 2],
+        )
+        .unwrap();
+        assert_eq!(
+            matrix,
+            new!(
+                4,
+                6,
+                2,
+                2,
+                2,
+                vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0],
+                vec![0, 2],
+                vec![0, 1, 2]
+            )
+        );
+        assert!(Block::from_parts((4, 5), 2, vec![1.0; 4], vec![0], vec![0, 1, 1]).is_err());
+        assert!(Block::from_parts((4, 6), 2, vec![1.0; 3], vec![0], vec![0, 1, 1]).is_err());
+        assert!(Block::from_parts((4, 6), 2, vec![1.0; 4], vec![3], vec![0, 1, 1]).is_err());
+        assert!(Block::from_parts((4, 6), 2, vec![1.0; 8], vec![1, 0], vec![0, 2, 2]).is_err());
+    }
+
+    #[test]
+    fn try_new() {
+        let matrix = Block::<f64>::try_new((4, 6), (2, 3)).unwrap();
+        assert_eq!(matrix.offsets, vec![0, 0, 0]);
+        assert!(Block::<f64>::try_new((4, 6), (3, 2)).is_err());
+        assert!(Block::<f64>::try_new((4, 6), (0, 2)).is_err());
+    }
+
+    #[test]
+    fn get() {
+        let conventional = Conventional::from_vec(
+            (4, 6),
+            matrix![
+                1.0, 3.0, 0.0, 0.0, 0.0, 0.0;
+                2.0, 4.0, 0.0, 0.0, 0.0, 0.0;
+                0.0, 0.0, 0.0, 0.0, 5.0, 0.0;
+                0.0, 0.0, 0.0, 0.0, 6.0, 8.0;
+            ],
+        );
+        let matrix = Block::from_conventional(&conventional, 2).unwrap();
+        assert_eq!(matrix.blocks, 2);
+        for i in 0..4 {
+            for j in 0..6 {
+                assert_eq!(conventional[(i, j)], matrix.get((i, j)));
+            }
+        }
+    }
+
+    #[test]
+    fn nonzeros() {
+        let matrix = new!(
+            4,
+            6,
+            2,
+            2,
+            2,
+            vec![1.0, 0.0, 3.0, 4.0, 0.0, 6.0, 0.0, 8.0],
+            vec![0, 2],
+            vec![0, 1, 2]
+        );
+        assert_eq!(matrix.nonzeros(), 5);
+    }
+}
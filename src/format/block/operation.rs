@@ -0,0 +1,163 @@
+use format::Block;
+use operation::{MultiplyInto, Transpose};
+use {Element, Number};
+
+impl<T> MultiplyInto<[T], [T]> for Block<T>
+where
+    T: Element + Number,
+{
+    #[inline]
+    fn multiply_into(&self, right: &[T], result: &mut [T]) {
+        let (m, p) = (self.rows, self.columns);
+        let n = right.len() / p;
+        multiply_matrix_left(self, right, result, m, p, n)
+    }
+}
+
+impl<T: Element> Transpose for Block<T> {
+    fn transpose(&self) -> Self {
+        let &Block {
+            rows,
+            columns,
+            block_rows,
+            block_columns,
+            blocks,
+            ref values,
+            ref indices,
+            ref offsets,
+        } = self;
+        let block_size = self.block_size();
+        let (major, minor) = (rows / block_rows, columns / block_columns);
+        let mut matrix = Block::new((columns, rows), (block_columns, block_rows));
+        for &j in indices {
+            matrix.offsets[j + 1] += 1;
+        }
+        for j in 0..minor {
+            matrix.offsets[j + 1] += matrix.offsets[j];
+        }
+        let mut next = matrix.offsets[..minor].to_vec();
+        matrix.blocks = blocks;
+        matrix.indices = vec![0; blocks];
+        matrix.values = vec![T::zero(); values.len()];
+        for i in 0..major {
+            for k in offsets[i]..offsets[i + 1] {
+                let l = next[indices[k]];
+                next[indices[k]] += 1;
+                matrix.indices[l] = i;
+                for jj in 0..block_columns {
+                    for ii in 0..block_rows {
+                        matrix.values[l * block_size + ii * block_columns + jj] =
+                            values[k * block_size + jj * block_rows + ii];
+                    }
+                }
+            }
+        }
+        matrix
+    }
+}
+
+fn multiply_matrix_left<T>(a: &Block<T>, b: &[T], c: &mut [T], m: usize, p: usize, n: usize)
+where
+    T: Element + Number,
+{
+    debug_assert_eq!(a.rows * a.columns, m * p);
+    debug_assert_eq!(b.len(), p * n);
+    debug_assert_eq!(c.len(), m * n);
+    let &Block {
+        block_rows,
+        block_columns,
+        ref values,
+        ref indices,
+        ref offsets,
+        ..
+    } = a;
+    let block_size = a.block_size();
+    for j in 0..n {
+        let (bo, co) = (j * p, j * m);
+        for i in 0..(m / block_rows) {
+            let co = co + i * block_rows;
+            for k in offsets[i]..offsets[i + 1] {
+                let bo = bo + indices[k] * block_columns;
+                let block = &values[(k * block_size)..((k + 1) * block_size)];
+                for l in 0..block_columns {
+                    let factor = b[bo + l];
+                    let column = &block[(l * block_rows)..((l + 1) * block_rows)];
+                    for (ii, &value) in column.iter().enumerate() {
+                        c[co + ii] = c[co + ii] + value * factor;
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use prelude::*;
+
+    #[test]
+    fn multiply_into() {
+        let matrix = Block::from_conventional(
+            &Conventional::from_vec(
+                (4, 6),
+                matrix![
+                    1.0, 3.0, 0.0, 0.0, 0.0, 0.0;
+                    2.0, 4.0, 0.0, 0.0, 0.0, 0.0;
+                    0.0, 1.0, 0.0, 0.0, 5.0, 7.0;
+                    0.0, 1.0, 0.0, 0.0, 6.0, 8.0;
+                ],
+            ),
+            2,
+        )
+        .unwrap();
+        let right = Conventional::from_vec(
+            (6, 2),
+            matrix![
+                1.0, 6.0;
+                2.0, 5.0;
+                3.0, 4.0;
+                4.0, 3.0;
+                5.0, 2.0;
+                6.0, 1.0;
+            ],
+        );
+        let mut result = Conventional::from_vec(
+            (4, 2),
+            matrix![
+                1.0, 1.0;
+                1.0, 1.0;
+                1.0, 1.0;
+                1.0, 1.0;
+            ],
+        );
+        matrix.multiply_into(&right, &mut result);
+        assert_eq!(
+            &result.values,
+            &matrix![
+                 8.0, 22.0;
+                11.0, 33.0;
+                70.0, 23.0;
+                81.0, 26.0;
+            ]
+        );
+    }
+
+    #[test]
+    fn transpose() {
+        let conventional = Conventional::from_vec(
+            (4, 6),
+            matrix![
+                1.0, 0.0, 0.0, 0.0, 0.0, 0.0;
+                0.0, 0.0, 2.0, 0.0, 0.0, 0.0;
+                0.0, 0.0, 0.0, 0.0, 0.0, 3.0;
+                0.0, 0.0, 0.0, 4.0, 0.0, 5.0;
+            ],
+        );
+        let matrix = Block::from_conventional(&conventional, (2, 3)).unwrap();
+        let matrix = matrix.transpose();
+        assert_eq!((matrix.block_rows, matrix.block_columns), (3, 2));
+        assert_eq!(matrix.offsets, vec![0, 1, 2]);
+        assert_eq!(matrix.indices, vec![0, 1]);
+        assert_eq!(Conventional::from(matrix), conventional.transpose());
+    }
+}
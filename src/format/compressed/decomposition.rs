@@ -0,0 +1,348 @@
+use num_traits::Float;
+
+use decomposition::{Condition, Solve};
+use format::compressed::Variant;
+use format::multiply::Columns;
+use format::Compressed;
+use {Element, Result};
+
+/// The LU decomposition of a compressed matrix.
+///
+/// The decomposition is computed column by column with partial pivoting in the
+/// left-looking manner of Gilbert and Peierls. The factors are sparse and
+/// stored in the compressed-column variant.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Lu<T: Element> {
+    /// The strictly lower triangle of `L`, whose diagonal is implicitly one.
+    pub lower: Compressed<T>,
+    /// The upper triangle of `U`. The diagonal element of each column is
+    /// stored last.
+    pub upper: Compressed<T>,
+    /// The pivot indices such that the `i`th row of `L × U` is the
+    /// `pivots[i]`th row of the original matrix.
+    pub pivots: Vec<usize>,
+}
+
+impl<T> Compressed<T>
+where
+    T: Element + Float,
+{
+    /// Perform the LU decomposition.
+    ///
+    /// The matrix should be square. The function fails when the matrix is
+    /// singular. The nonzero pattern of each column of the factors is found
+    /// by a depth-first search in the graph of `L`, so the time taken is
+    /// proportional to the number of floating-point operations plus the
+    /// number of nonzero elements.
+    pub fn lu(&self) -> Result<Lu<T>> {
+        let &Compressed { rows, columns, .. } = validate!(self);
+        if rows != columns {
+            raise!(format!(
+                "the LU decomposition requires a square matrix but it is {} × {}",
+                rows, columns
+            ));
+        }
+        let (n, matrix) = (rows, self.columnar());
+        let zero: T = Element::zero();
+        let mut pivots = Vec::with_capacity(n);
+        let mut positions = vec![None; n];
+        let mut work = vec![zero; n];
+        let (mut marks, mut stack, mut reach) = (vec![n; n], vec![], vec![]);
+        let (mut pattern, mut column) = (vec![], vec![]);
+        let (mut lower_values, mut lower_indices, mut lower_offsets) = (vec![], vec![], vec![0]);
+        let (mut upper_values, mut upper_indices, mut upper_offsets) = (vec![], vec![], vec![0]);
+        for j in 0..n {
+            pattern.clear();
+            matrix.column(j, |i, value| {
+                work[i] = value;
+                pattern.push(i);
+            });
+            reach.clear();
+            for &i in &pattern {
+                let graph = (&lower_indices[..], &lower_offsets[..], &positions[..]);
+                visit(i, j, graph, &mut marks, &mut stack, &mut reach);
+            }
+            column.clear();
+            for &i in reach.iter().rev() {
+                let k = match positions[i] {
+                    Some(k) => k,
+                    _ => continue,
+                };
+                let value = work[i];
+                work[i] = zero;
+                if Element::is_zero(&value) {
+                    continue;
+                }
+                for l in lower_offsets[k]..lower_offsets[k + 1] {
+                    let i = lower_indices[l];
+                    work[i] = work[i] - lower_values[l] * value;
+                }
+                column.push((k, value));
+            }
+            column.sort_by_key(|&(k, _)| k);
+            for &(k, value) in &column {
+                upper_values.push(value);
+                upper_indices.push(k);
+            }
+            let (mut pivot, mut largest) = (n, 0.0);
+            for &i in &reach {
+                if positions[i].is_none() && work[i].magnitude() > largest {
+                    pivot = i;
+                    largest = work[i].magnitude();
+                }
+            }
+            if pivot == n {
+                raise!(format!("the matrix is singular (column {})", j));
+            }
+            let diagonal = work[pivot];
+            work[pivot] = zero;
+            positions[pivot] = Some(j);
+            pivots.push(pivot);
+            upper_values.push(diagonal);
+            upper_indices.push(j);
+            upper_offsets.push(upper_values.len());
+            for &i in &reach {
+                let value = work[i];
+                if !Element::is_zero(&value) {
+                    lower_values.push(value / diagonal);
+                    lower_indices.push(i);
+                    work[i] = zero;
+                }
+            }
+            lower_offsets.push(lower_values.len());
+        }
+        for j in 0..n {
+            let (from, until) = (lower_offsets[j], lower_offsets[j + 1]);
+            let mut column = (from..until)
+                .map(|l| (positions[lower_indices[l]].unwrap(), lower_values[l]))
+                .collect::<Vec<_>>();
+            column.sort_by_key(|&(i, _)| i);
+            for (l, (i, value)) in (from..until).zip(column) {
+                lower_indices[l] = i;
+                lower_values[l] = value;
+            }
+        }
+        Ok(Lu {
+            lower: new!(
+                n,
+                n,
+                lower_values.len(),
+                Variant::Column,
+                lower_values,
+                lower_indices,
+                lower_offsets
+            ),
+            upper: new!(
+                n,
+                n,
+                upper_values.len(),
+                Variant::Column,
+                upper_values,
+                upper_indices,
+                upper_offsets
+            ),
+            pivots,
+        })
+    }
+}
+
+// Append to `reach` the rows reachable from a row in the graph whose edges go
+// from each pivot row to the rows of the corresponding column of `L`. The rows
+// are appended in the reversed topological order, and the visited ones are
+// marked with `stamp` so that each row is appended at most once.
+fn visit(
+    root: usize,
+    stamp: usize,
+    graph: (&[usize], &[usize], &[Option<usize>]),
+    marks: &mut [usize],
+    stack: &mut Vec<(usize, usize, usize)>,
+    reach: &mut Vec<usize>,
+) {
+    let (indices, offsets, positions) = graph;
+    let edges = |i: usize| match positions[i] {
+        Some(k) => (offsets[k], offsets[k + 1]),
+        _ => (0, 0),
+    };
+    if marks[root] == stamp {
+        return;
+    }
+    marks[root] = stamp;
+    let (from, until) = edges(root);
+    stack.push((root, from, until));
+    while let Some((i, mut next, until)) = stack.pop() {
+        let mut leaf = true;
+        while next < until {
+            let child = indices[next];
+            next += 1;
+            if marks[child] != stamp {
+                marks[child] = stamp;
+                stack.push((i, next, until));
+                let (from, until) = edges(child);
+                stack.push((child, from, until));
+                leaf = false;
+                break;
+            }
+        }
+        if leaf {
+            reach.push(i);
+        }
+    }
+}
+
+impl<T> Condition<T> for Lu<T>
+where
+    T: Element + Float,
+{
+    #[inline]
+    fn order(&self) -> usize {
+        self.upper.rows
+    }
+}
+
+impl<T> Solve<T> for Lu<T>
+where
+    T: Element + Float,
+{
+    fn solve(&self, right: &mut [T]) {
+        let (lower, upper) = (&self.lower, &self.upper);
+        let n = upper.rows;
+        if n == 0 {
+            return;
+        }
+        debug_assert_eq!(right.len() % n, 0);
+        let mut y = vec![Element::zero(); n];
+        for b in right.chunks_mut(n) {
+            for (k, &i) in self.pivots.iter().enumerate() {
+                y[k] = b[i];
+            }
+            for j in 0..n {
+                let value = y[j];
+                for l in lower.offsets[j]..lower.offsets[j + 1] {
+                    let i = lower.indices[l];
+                    y[i] = y[i] - lower.values[l] * value;
+                }
+            }
+            for j in (0..n).rev() {
+                let (from, until) = (upper.offsets[j], upper.offsets[j + 1] - 1);
+                y[j] = y[j] / upper.values[until];
+                let value = y[j];
+                for l in from..until {
+                    let i = upper.indices[l];
+                    y[i] = y[i] - upper.values[l] * value;
+                }
+            }
+            b.copy_from_slice(&y);
+        }
+    }
+
+    fn solve_transpose(&self, right: &mut [T]) {
+        let (lower, upper) = (&self.lower, &self.upper);
+        let n = upper.rows;
+        if n == 0 {
+            return;
+        }
+        debug_assert_eq!(right.len() % n, 0);
+        let mut y = vec![Element::zero(); n];
+        for b in right.chunks_mut(n) {
+            for j in 0..n {
+                let (from, until) = (upper.offsets[j], upper.offsets[j + 1] - 1);
+                let mut sum = b[j];
+                for l in from..until {
+                    sum = sum - upper.values[l] * y[upper.indices[l]];
+                }
+                y[j] = sum / upper.values[until];
+            }
+            for j in (0..n).rev() {
+                let mut sum = y[j];
+                for l in lower.offsets[j]..lower.offsets[j + 1] {
+                    sum = sum - lower.values[l] * y[lower.indices[l]];
+                }
+                y[j] = sum;
+            }
+            for (k, &i) in self.pivots.iter().enumerate() {
+                b[i] = y[k];
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use assert;
+
+    use decomposition::{Condition, Solve};
+    use format::compressed::Variant;
+    use prelude::*;
+
+    #[test]
+    fn lu() {
+        let mut matrix = Compressed::new(4, Variant::Row);
+        matrix.set((0, 1), 2.0);
+        matrix.set((0, 3), 1.0);
+        matrix.set((1, 0), 1.0);
+        matrix.set((1, 2), 3.0);
+        matrix.set((2, 0), 4.0);
+        matrix.set((2, 1), 1.0);
+        matrix.set((3, 2), -1.0);
+        matrix.set((3, 3), 5.0);
+        let lu = matrix.lu().unwrap();
+        assert_eq!(lu.pivots, vec![2, 0, 1, 3]);
+
+        let dense = Conventional::from(matrix);
+        let expected = vec![1.0, -2.0, 0.5, 3.0, 2.0, 0.0, -1.0, 1.0];
+        let mut right = vec![0.0; 8];
+        dense.multiply_into(&expected[..], &mut right);
+        lu.solve(&mut right);
+        assert::close(&right, &expected, 1e-14);
+
+        let mut right = vec![0.0; 8];
+        dense.transpose().multiply_into(&expected[..], &mut right);
+        lu.solve_transpose(&mut right);
+        assert::close(&right, &expected, 1e-14);
+
+        let mut matrix = Compressed::new(2, Variant::Column);
+        matrix.set((0, 0), 1.0);
+        matrix.set((1, 0), 2.0);
+        assert!(matrix.lu().is_err());
+    }
+
+    #[test]
+    fn lu_fill() {
+        let n = 12;
+        let mut matrix = Compressed::new(n, Variant::Column);
+        for i in 0..n {
+            matrix.set((i, i), 0.1 * (i + 1) as f64);
+            matrix.set((i, (i * 5 + 3) % n), 1.0 + i as f64);
+            matrix.set(((i * 7 + 2) % n, i), -2.0 + 0.5 * i as f64);
+        }
+        let lu = matrix.lu().unwrap();
+        let mut sorted = lu.pivots.clone();
+        sorted.sort();
+        assert_eq!(sorted, (0..n).collect::<Vec<_>>());
+
+        let dense = Conventional::from(matrix);
+        let expected = (0..(2 * n)).map(|i| i as f64 - 5.0).collect::<Vec<_>>();
+        let mut right = vec![0.0; 2 * n];
+        dense.multiply_into(&expected[..], &mut right);
+        lu.solve(&mut right);
+        assert::close(&right, &expected, 1e-10);
+    }
+
+    #[test]
+    fn estimate_condition() {
+        let matrix = Compressed::from(Conventional::from_vec(
+            3,
+            matrix![
+                4.0, 1.0, 0.0;
+                2.0, 5.0, 1.0;
+                0.0, 3.0, 6.0;
+            ],
+        ));
+        let lu = matrix.lu().unwrap();
+        assert::close(
+            lu.estimate_condition(matrix.norm_one()),
+            135.0 / 32.0,
+            1e-14,
+        );
+    }
+}
@@ -11,7 +11,7 @@
 
 use std::{iter, mem};
 
-use {Element, Matrix, Position, Size};
+use {Element, Matrix, Position, Result, Size};
 
 /// A compressed matrix.
 #[derive(Clone, Debug, PartialEq)]
@@ -53,8 +53,11 @@ macro_rules! new(
 );
 
 mod convert;
+mod decomposition;
 mod operation;
 
+pub use self::decomposition::Lu;
+
 #[cfg(debug_assertions)]
 impl<T: Element> ::format::Validate for Compressed<T> {
     fn validate(&self) {
@@ -117,6 +120,98 @@ impl<T: Element> Compressed<T> {
         )
     }
 
+    /// Create a matrix from its parts checking that they are consistent.
+    ///
+    /// The offsets should start with zero, be nondecreasing, and end with the
+    /// number of values, and the indices within each column when `variant =
+    /// Column` or row when `variant = Row` should be strictly increasing and
+    /// within the bounds of the matrix.
+    pub fn from_parts<S: Size>(
+        size: S,
+        variant: Variant,
+        values: Vec<T>,
+        indices: Vec<usize>,
+        offsets: Vec<usize>,
+    ) -> Result<Self> {
+        let (rows, columns) = size.dimensions();
+        let (major, minor) = match variant {
+            Variant::Column => (columns, rows),
+            Variant::Row => (rows, columns),
+        };
+        let nonzeros = values.len();
+        if indices.len() != nonzeros {
+            raise!(format!(
+                "there are {} values but {} indices",
+                nonzeros,
+                indices.len()
+            ));
+        }
+        if offsets.len() != major + 1 {
+            raise!(format!(
+                "there should be {} offsets but {} have been given",
+                major + 1,
+                offsets.len()
+            ));
+        }
+        if offsets[0] != 0 || offsets[major] != nonzeros {
+            raise!(format!(
+                "the offsets should start with 0 and end with {}",
+                nonzeros
+            ));
+        }
+        for l in 0..major {
+            let (start, finish) = (offsets[l], offsets[l + 1]);
+            if start > finish || finish > nonzeros {
+                raise!(format!("the offsets are not nondecreasing at {}", l));
+            }
+            for k in start..finish {
+                if indices[k] >= minor {
+                    raise!(format!(
+                        "the index {} is out of bounds ({})",
+                        indices[k], minor
+                    ));
+                }
+                if k > start && indices[k - 1] >= indices[k] {
+                    raise!(format!("the indices are not strictly increasing at {}", k));
+                }
+            }
+        }
+        Ok(new!(
+            rows, columns, nonzeros, variant, values, indices, offsets
+        ))
+    }
+
+    // Create a matrix from elements given in the storage order without
+    // duplicates.
+    pub(super) fn assemble<S: Size>(
+        size: S,
+        variant: Variant,
+        elements: Vec<(usize, usize, T)>,
+    ) -> Self {
+        let (rows, columns) = size.dimensions();
+        let major = match variant {
+            Variant::Column => columns,
+            Variant::Row => rows,
+        };
+        let nonzeros = elements.len();
+        let mut values = Vec::with_capacity(nonzeros);
+        let mut indices = Vec::with_capacity(nonzeros);
+        let mut offsets = vec![0; major + 1];
+        for (i, j, value) in elements {
+            let (major, minor) = match variant {
+                Variant::Column => (j, i),
+                Variant::Row => (i, j),
+            };
+            offsets[major + 1] += 1;
+            values.push(value);
+            indices.push(minor);
+        }
+        for k in 0..major {
+            offsets[k + 1] += offsets[k];
+        }
+        new!(rows, columns, nonzeros, variant, values, indices, offsets)
+    }
+
     /// Read an element.
     pub fn get<P: Position>(&self, position: P) -> T {
         let (mut i, mut j) = position.coordinates();
@@ -342,6 +437,91 @@ mod tests {
         assert_eq!(conventional, (&matrix).into());
     }
 
+    #[test]
+    fn from_parts() {
+        let matrix = Compressed::from_parts(
+            (5, 7),
+            Variant::Column,
+            vec![1.0, 2.0, 3.0, 4.0, 5.0],
+            vec![1, 0, 3, 1, 4],
+            vec![0, 0, 0, 1, 2, 2, 3, 5],
+        )
+        .unwrap();
+        assert_eq!(
+            matrix,
+            new!(
+                5,
+                7,
+                5,
+                Variant::Column,
+                vec![1.0, 2.0, 3.0, 4.0, 5.0],
+                vec![1, 0, 3, 1, 4],
+                vec![0, 0, 0, 1, 2, 2, 3, 5]
+            )
+        );
+    }
+
+    #[test]
+    fn from_parts_invalid() {
+        macro_rules! check(
+            ($size:expr, $variant:expr, $values:expr, $indices:expr, $offsets:expr) => (
+                assert!(
+                    Compressed::<f64>::from_parts($size, $variant, $values, $indices, $offsets)
+                        .is_err()
+                );
+            );
+        );
+        check!(
+            (2, 2),
+            Variant::Column,
+            vec![1.0, 2.0],
+            vec![0],
+            vec![0, 1, 2]
+        );
+        check!(
+            (2, 2),
+            Variant::Column,
+            vec![1.0, 2.0],
+            vec![0, 1],
+            vec![0, 2]
+        );
+        check!(
+            (2, 2),
+            Variant::Column,
+            vec![1.0, 2.0],
+            vec![0, 1],
+            vec![0, 1, 1]
+        );
+        check!(
+            (2, 2),
+            Variant::Column,
+            vec![1.0, 2.0],
+            vec![0, 1],
+            vec![0, 3, 2]
+        );
+        check!(
+            (2, 2),
+            Variant::Column,
+            vec![1.0, 2.0],
+            vec![0, 2],
+            vec![0, 1, 2]
+        );
+        check!(
+            (2, 3),
+            Variant::Row,
+            vec![1.0, 2.0],
+            vec![1, 1],
+            vec![0, 2, 2]
+        );
+        check!(
+            (2, 3),
+            Variant::Row,
+            vec![1.0, 2.0],
+            vec![2, 1],
+            vec![0, 2, 2]
+        );
+    }
+
     #[test]
     fn nonzeros() {
         let matrix = new!(
@@ -1,3 +1,7 @@
+use std::borrow::Cow;
+
+use format::compressed::Variant;
+use format::multiply::Columns;
 use format::{Compressed, Conventional, Diagonal};
 use operation::{Multiply, MultiplyInto, MultiplySelf, Transpose};
 use {Element, Number};
@@ -26,6 +30,16 @@ where
     }
 }
 
+impl<T> MultiplyInto<Vec<T>, [T]> for Compressed<T>
+where
+    T: Element + Number,
+{
+    #[inline(always)]
+    fn multiply_into(&self, right: &Vec<T>, result: &mut [T]) {
+        MultiplyInto::multiply_into(self, right as &[T], result)
+    }
+}
+
 impl<'l, T> MultiplyInto<Compressed<T>, [T]> for Conventional<T>
 where
     T: Element + Number,
@@ -51,6 +65,46 @@ where
     }
 }
 
+impl<T: Element> Columns<T> for Compressed<T> {
+    // The row variant is supported but requires a search in each row; hence,
+    // traversing all columns should be done on the result of `columnar`.
+    fn column<F>(&self, j: usize, mut callback: F)
+    where
+        F: FnMut(usize, T),
+    {
+        let &Compressed {
+            rows,
+            variant,
+            ref values,
+            ref indices,
+            ref offsets,
+            ..
+        } = self;
+        match variant {
+            Variant::Column => {
+                for k in offsets[j]..offsets[j + 1] {
+                    callback(indices[k], values[k]);
+                }
+            }
+            Variant::Row => {
+                for i in 0..rows {
+                    let offset = offsets[i];
+                    if let Ok(k) = indices[offset..offsets[i + 1]].binary_search(&j) {
+                        callback(i, values[offset + k]);
+                    }
+                }
+            }
+        }
+    }
+
+    fn columnar<'l>(&'l self) -> Cow<'l, Self> {
+        match self.variant {
+            Variant::Column => Cow::Borrowed(self),
+            Variant::Row => Cow::Owned(flip(self)),
+        }
+    }
+}
+
 impl<T: Element> Transpose for Compressed<T> {
     fn transpose(&self) -> Self {
         let &Compressed {
@@ -68,6 +122,49 @@ impl<T: Element> Transpose for Compressed<T> {
     }
 }
 
+// Convert the matrix into the other variant. The elements are distributed by
+// a counting sort, which preserves the order of the minor indices.
+fn flip<T: Element>(matrix: &Compressed<T>) -> Compressed<T> {
+    let &Compressed {
+        rows,
+        columns,
+        nonzeros,
+        variant,
+        ref values,
+        ref indices,
+        ref offsets,
+    } = matrix;
+    let (major, minor) = match variant {
+        Variant::Column => (columns, rows),
+        Variant::Row => (rows, columns),
+    };
+    let mut result = new!(
+        rows,
+        columns,
+        nonzeros,
+        variant.flip(),
+        vec![T::zero(); nonzeros],
+        vec![0; nonzeros],
+        vec![0; minor + 1]
+    );
+    for &i in &indices[..nonzeros] {
+        result.offsets[i + 1] += 1;
+    }
+    for k in 0..minor {
+        result.offsets[k + 1] += result.offsets[k];
+    }
+    let mut positions = result.offsets.clone();
+    for l in 0..major {
+        for k in offsets[l]..offsets[l + 1] {
+            let position = &mut positions[indices[k]];
+            result.values[*position] = values[k];
+            result.indices[*position] = l;
+            *position += 1;
+        }
+    }
+    result
+}
+
 fn multiply_matrix_left<T>(a: &Compressed<T>, b: &[T], c: &mut [T], m: usize, p: usize, n: usize)
 where
     T: Element + Number,
@@ -75,12 +172,13 @@ where
     debug_assert_eq!(a.rows * a.columns, m * p);
     debug_assert_eq!(b.len(), p * n);
     debug_assert_eq!(c.len(), m * n);
+    let a = a.columnar();
     let &Compressed {
         ref values,
         ref indices,
         ref offsets,
         ..
-    } = a;
+    } = &*a;
     for j in 0..n {
         let bo = j * p;
         let co = j * m;
@@ -101,12 +199,13 @@ where
     debug_assert_eq!(a.len(), m * p);
     debug_assert_eq!(b.rows * b.columns, p * n);
     debug_assert_eq!(c.len(), m * n);
+    let b = b.columnar();
     let &Compressed {
         ref values,
         ref indices,
         ref offsets,
         ..
-    } = b;
+    } = &*b;
     for j in 0..n {
         let co = j * m;
         for k in offsets[j]..offsets[j + 1] {
@@ -121,6 +220,7 @@ where
 #[cfg(test)]
 mod tests {
     use format::compressed::Variant;
+    use format::multiply::Columns;
     use prelude::*;
 
     #[test]
@@ -230,6 +330,31 @@ mod tests {
         );
     }
 
+    #[test]
+    fn columnar() {
+        let mut matrix = Compressed::new((3, 4), Variant::Row);
+        matrix.set((0, 3), 1.0);
+        matrix.set((1, 0), 2.0);
+        matrix.set((1, 3), 3.0);
+        matrix.set((2, 1), 4.0);
+        let columnar = matrix.columnar();
+        assert_eq!(
+            *columnar,
+            new!(
+                3,
+                4,
+                4,
+                Variant::Column,
+                vec![2.0, 4.0, 1.0, 3.0],
+                vec![1, 2, 0, 1],
+                vec![0, 1, 2, 2, 4]
+            )
+        );
+        let mut values = vec![];
+        matrix.column(3, |i, value| values.push((i, value)));
+        assert_eq!(values, vec![(0, 1.0), (1, 3.0)]);
+    }
+
     #[test]
     fn transpose() {
         let matrix = new!(
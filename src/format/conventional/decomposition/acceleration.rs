@@ -2,6 +2,7 @@ use lapack as backend;
 
 use decomposition::{SingularValue, SymmetricEigen};
 use format::{Conventional, Diagonal};
+use operation::{Multiply, Transpose};
 use Result;
 
 macro_rules! success(
@@ -14,6 +15,77 @@ macro_rules! success(
     );
 );
 
+impl Conventional<f64> {
+    /// Compute the 2-norm, which is the largest singular value.
+    pub fn norm2(&self) -> Result<f64> {
+        let (_, values, _) = SingularValue::decompose(self)?;
+        Ok(values.first().cloned().unwrap_or(0.0))
+    }
+
+    /// Compute the 2-norm condition number, which is the ratio of the largest
+    /// singular value to the smallest one.
+    ///
+    /// The condition number of a rank-deficient matrix is infinite.
+    pub fn condition_number(&self) -> Result<f64> {
+        let (_, values, _) = SingularValue::decompose(self)?;
+        Ok(match (values.first(), values.last()) {
+            (Some(&largest), Some(&smallest)) => largest / smallest,
+            _ => 0.0,
+        })
+    }
+
+    /// Compute the Moore–Penrose pseudoinverse.
+    ///
+    /// The singular values not exceeding `tolerance` are treated as zeros. When
+    /// `tolerance` is `None`, the default tolerance is `max(m, n) × ε × σ₁`
+    /// where `m × n` is the size of the matrix, `ε` is the machine epsilon,
+    /// and `σ₁` is the largest singular value.
+    pub fn pseudo_inverse(&self, tolerance: Option<f64>) -> Result<Self> {
+        let (left, values, right) = SingularValue::decompose(self)?;
+        let tolerance = threshold(&values, tolerance);
+        let middle = right
+            .transpose()
+            .multiply(&values.pseudo_inverse(tolerance));
+        Ok(middle.multiply(&left.transpose()))
+    }
+
+    /// Compute the rank, which is the number of singular values exceeding
+    /// `tolerance`.
+    ///
+    /// The default tolerance is the one of `pseudo_inverse`.
+    pub fn rank(&self, tolerance: Option<f64>) -> Result<usize> {
+        let (_, values, _) = SingularValue::decompose(self)?;
+        Ok(rank(&values, tolerance))
+    }
+
+    /// Compute an orthonormal basis of the null space.
+    ///
+    /// The basis vectors are stored as columns. The default tolerance is the
+    /// one of `pseudo_inverse`.
+    pub fn null_space(&self, tolerance: Option<f64>) -> Result<Self> {
+        let (_, values, right) = SingularValue::decompose(self)?;
+        let (n, r) = (self.columns, rank(&values, tolerance));
+        let right = right.transpose();
+        Ok(Conventional::from_vec(
+            (n, n - r),
+            right.values[(r * n)..].to_vec(),
+        ))
+    }
+
+    /// Compute an orthonormal basis of the range, which is the column space.
+    ///
+    /// The basis vectors are stored as columns. The default tolerance is the
+    /// one of `pseudo_inverse`.
+    pub fn range(&self, tolerance: Option<f64>) -> Result<Self> {
+        let (left, values, _) = SingularValue::decompose(self)?;
+        let (m, r) = (self.rows, rank(&values, tolerance));
+        Ok(Conventional::from_vec(
+            (m, r),
+            left.values[..(r * m)].to_vec(),
+        ))
+    }
+}
+
 impl SingularValue<f64> for Conventional<f64> {
     fn decompose(&self) -> Result<(Conventional<f64>, Diagonal<f64>, Conventional<f64>)> {
         let (m, n) = (self.rows, self.columns);
@@ -42,6 +114,11 @@ impl SymmetricEigen<f64> for Conventional<f64> {
     }
 }
 
+fn rank(values: &Diagonal<f64>, tolerance: Option<f64>) -> usize {
+    let tolerance = threshold(values, tolerance);
+    values.iter().filter(|&&value| value > tolerance).count()
+}
+
 fn singular_value(
     matrix: &[f64],
     left: &mut [f64],
@@ -102,6 +179,13 @@ fn singular_value(
     Ok(())
 }
 
+fn threshold(values: &Diagonal<f64>, tolerance: Option<f64>) -> f64 {
+    tolerance.unwrap_or_else(|| {
+        let largest = values.first().cloned().unwrap_or(0.0);
+        max!(values.rows, values.columns) as f64 * f64::EPSILON * largest
+    })
+}
+
 fn symmetric_eigen(matrix: &mut [f64], values: &mut [f64], m: usize) -> Result<()> {
     debug_assert_eq!(matrix.len(), m * m);
     debug_assert_eq!(values.len(), m);
@@ -133,6 +217,67 @@ mod tests {
     use assert;
     use prelude::*;
 
+    #[test]
+    fn norm2() {
+        let matrix = Conventional::from_vec(
+            2,
+            matrix![
+                3.0, 0.0;
+                4.0, 5.0;
+            ],
+        );
+        assert::close(matrix.norm2().unwrap(), 45f64.sqrt(), 1e-14);
+        assert::close(matrix.condition_number().unwrap(), 3.0, 1e-14);
+        let matrix = Conventional::from_vec(2, vec![1.0, 2.0, 2.0, 4.0]);
+        assert_eq!(matrix.condition_number().unwrap(), f64::INFINITY);
+    }
+
+    #[test]
+    fn pseudo_inverse() {
+        let matrix = Conventional::from_vec(
+            (3, 2),
+            matrix![
+                1.0, 2.0;
+                2.0, 4.0;
+                3.0, 6.0;
+            ],
+        );
+        assert_eq!(matrix.rank(None).unwrap(), 1);
+        assert_eq!(matrix.rank(Some(100.0)).unwrap(), 0);
+        assert::close(
+            &*matrix.pseudo_inverse(None).unwrap(),
+            &*Conventional::from_vec(
+                (2, 3),
+                matrix![
+                    1.0 / 70.0, 2.0 / 70.0, 3.0 / 70.0;
+                    2.0 / 70.0, 4.0 / 70.0, 6.0 / 70.0;
+                ],
+            ),
+            1e-14,
+        );
+    }
+
+    #[test]
+    fn range_and_null_space() {
+        let matrix = Conventional::from_vec(
+            (3, 2),
+            matrix![
+                1.0, 2.0;
+                2.0, 4.0;
+                3.0, 6.0;
+            ],
+        );
+        let range = matrix.range(None).unwrap();
+        assert_eq!((range.rows, range.columns), (3, 1));
+        let scale = range.values[0].signum() / 14f64.sqrt();
+        assert::close(&*range, &[scale, 2.0 * scale, 3.0 * scale][..], 1e-14);
+
+        let null = matrix.null_space(None).unwrap();
+        assert_eq!((null.rows, null.columns), (2, 1));
+        let scale = null.values[0].signum() / 5f64.sqrt();
+        assert::close(&*null, &[2.0 * scale, -scale][..], 1e-14);
+    }
+
     #[test]
     fn singular_value() {
         let matrix = Conventional::from_vec(
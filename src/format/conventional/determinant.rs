@@ -0,0 +1,398 @@
+use format::Conventional;
+use {c32, c64};
+use {Element, Number, Result};
+
+/// An element type admitting determinants and inverses.
+///
+/// Real and complex elements are eliminated with partial pivoting. Integer
+/// elements are eliminated with the fraction-free algorithm of Bareiss, which
+/// keeps all intermediate values exact.
+pub trait Determinant: Element + Number {
+    /// Compute the determinant of a square matrix.
+    fn determinant(matrix: &Conventional<Self>) -> Self;
+
+    /// Compute the sign and the natural logarithm of the absolute value of the
+    /// determinant of a square matrix.
+    fn log_abs_determinant(matrix: &Conventional<Self>) -> (Self, f64);
+
+    /// Compute the inverse of a square matrix.
+    fn inverse(matrix: &Conventional<Self>) -> Result<Conventional<Self>>;
+}
+
+impl<T: Determinant> Conventional<T> {
+    /// Compute the determinant.
+    ///
+    /// The matrix should be square. The determinant of a singular matrix is
+    /// zero.
+    pub fn determinant(&self) -> Result<T> {
+        square(self)?;
+        Ok(Determinant::determinant(self))
+    }
+
+    /// Compute the sign and the natural logarithm of the absolute value of the
+    /// determinant.
+    ///
+    /// The sign is one or minus one for real elements and has unit modulus for
+    /// complex ones. The matrix should be square. For a singular matrix, the
+    /// sign is zero, and the logarithm is negative infinity.
+    pub fn log_abs_determinant(&self) -> Result<(T, f64)> {
+        square(self)?;
+        Ok(Determinant::log_abs_determinant(self))
+    }
+
+    /// Compute the inverse.
+    ///
+    /// The matrix should be square. The function fails when the matrix is
+    /// singular or, for integer elements, when the inverse is not integer.
+    pub fn inverse(&self) -> Result<Self> {
+        square(self)?;
+        Determinant::inverse(self)
+    }
+}
+
+macro_rules! floating(
+    ($kind:ty, $value:ident => $sign:expr) => (
+        impl Determinant for $kind {
+            fn determinant(matrix: &Conventional<Self>) -> Self {
+                let mut values = matrix.values.clone();
+                match factorize(&mut values, matrix.rows) {
+                    Some(odd) => {
+                        let n = matrix.rows;
+                        let product = (0..n).fold(Self::one(), |product, i| {
+                            product * values[i * n + i]
+                        });
+                        if odd {
+                            -product
+                        } else {
+                            product
+                        }
+                    }
+                    _ => Self::zero(),
+                }
+            }
+
+            fn log_abs_determinant(matrix: &Conventional<Self>) -> (Self, f64) {
+                let mut values = matrix.values.clone();
+                match factorize(&mut values, matrix.rows) {
+                    Some(odd) => {
+                        let n = matrix.rows;
+                        let one = if odd { -Self::one() } else { Self::one() };
+                        (0..n).fold((one, 0.0), |(sign, logarithm), i| {
+                            let $value = values[i * n + i];
+                            (sign * $sign, logarithm + $value.magnitude().ln())
+                        })
+                    }
+                    _ => (Self::zero(), f64::NEG_INFINITY),
+                }
+            }
+
+            fn inverse(matrix: &Conventional<Self>) -> Result<Conventional<Self>> {
+                let n = matrix.rows;
+                let mut values = matrix.values.clone();
+                let mut result = identity(n);
+                for k in 0..n {
+                    let p = pivot(&values, n, k);
+                    if values[k * n + p].is_zero() {
+                        raise!(format!("the matrix is singular (column {})", k));
+                    }
+                    swap(&mut values, n, k, p);
+                    swap(&mut result, n, k, p);
+                    let scale = Self::one() / values[k * n + k];
+                    for j in 0..n {
+                        values[j * n + k] *= scale;
+                        result[j * n + k] *= scale;
+                    }
+                    for i in (0..n).filter(|&i| i != k) {
+                        let factor = values[k * n + i];
+                        if factor.is_zero() {
+                            continue;
+                        }
+                        for j in 0..n {
+                            let (value, other) = (values[j * n + k], result[j * n + k]);
+                            values[j * n + i] -= factor * value;
+                            result[j * n + i] -= factor * other;
+                        }
+                    }
+                }
+                Ok(new!(n, n, result))
+            }
+        }
+    );
+);
+
+floating!(f32, value => if value < 0.0 { -1.0 } else { 1.0 });
+floating!(f64, value => if value < 0.0 { -1.0 } else { 1.0 });
+floating!(c32, value => value.unscale(value.magnitude() as f32));
+floating!(c64, value => value.unscale(value.magnitude()));
+
+macro_rules! integer(
+    ($kind:ty) => (
+        impl Determinant for $kind {
+            fn determinant(matrix: &Conventional<Self>) -> Self {
+                let n = matrix.rows;
+                if n == 0 {
+                    return 1;
+                }
+                let mut values = matrix.values.clone();
+                let (mut sign, mut previous) = (1, 1);
+                for k in 0..(n - 1) {
+                    let p = pivot(&values, n, k);
+                    if values[k * n + p] == 0 {
+                        return 0;
+                    }
+                    if p != k {
+                        swap(&mut values, n, k, p);
+                        sign = -sign;
+                    }
+                    let current = values[k * n + k];
+                    for j in (k + 1)..n {
+                        for i in (k + 1)..n {
+                            values[j * n + i] = (current * values[j * n + i]
+                                - values[k * n + i] * values[j * n + k])
+                                / previous;
+                        }
+                    }
+                    previous = current;
+                }
+                sign * values[n * n - 1]
+            }
+
+            fn log_abs_determinant(matrix: &Conventional<Self>) -> (Self, f64) {
+                let determinant = Determinant::determinant(matrix);
+                (determinant.signum(), (determinant as f64).abs().ln())
+            }
+
+            // The fraction-free Gauss–Jordan elimination turns the matrix
+            // into the determinant times the identity matrix and the identity
+            // matrix into the adjugate matrix, both up to the sign of the row
+            // permutation, which cancels out in the final division.
+            fn inverse(matrix: &Conventional<Self>) -> Result<Conventional<Self>> {
+                let n = matrix.rows;
+                let mut values = matrix.values.clone();
+                let mut result = identity(n);
+                let mut previous = 1;
+                for k in 0..n {
+                    let p = pivot(&values, n, k);
+                    if values[k * n + p] == 0 {
+                        raise!(format!("the matrix is singular (column {})", k));
+                    }
+                    swap(&mut values, n, k, p);
+                    swap(&mut result, n, k, p);
+                    let current = values[k * n + k];
+                    for i in (0..n).filter(|&i| i != k) {
+                        let factor = values[k * n + i];
+                        for j in 0..n {
+                            values[j * n + i] = (current * values[j * n + i]
+                                - factor * values[j * n + k])
+                                / previous;
+                            result[j * n + i] = (current * result[j * n + i]
+                                - factor * result[j * n + k])
+                                / previous;
+                        }
+                    }
+                    previous = current;
+                }
+                for i in 0..n {
+                    let scale = values[i * n + i];
+                    for j in 0..n {
+                        let value = &mut result[j * n + i];
+                        if *value % scale != 0 {
+                            raise!("the inverse is not integer");
+                        }
+                        *value /= scale;
+                    }
+                }
+                Ok(new!(n, n, result))
+            }
+        }
+    );
+);
+
+integer!(i8);
+integer!(i16);
+integer!(i32);
+integer!(i64);
+integer!(isize);
+
+// Reduce the matrix to the upper-triangular form in place and return whether
+// the number of row interchanges is odd or nothing if the matrix is singular.
+fn factorize<T: Element + Number>(values: &mut [T], n: usize) -> Option<bool> {
+    let mut odd = false;
+    for k in 0..n {
+        let p = pivot(values, n, k);
+        let current = values[k * n + p];
+        if Element::is_zero(&current) {
+            return None;
+        }
+        if p != k {
+            swap(values, n, k, p);
+            odd = !odd;
+        }
+        for i in (k + 1)..n {
+            let factor = values[k * n + i] / current;
+            for j in k..n {
+                values[j * n + i] = values[j * n + i] - factor * values[j * n + k];
+            }
+        }
+    }
+    Some(odd)
+}
+
+fn identity<T: Element>(n: usize) -> Vec<T> {
+    let mut values = vec![T::zero(); n * n];
+    for i in 0..n {
+        values[i * n + i] = T::one();
+    }
+    values
+}
+
+// Find the row with the largest magnitude in the `k`th column starting from
+// the `k`th row.
+fn pivot<T: Element>(values: &[T], n: usize, k: usize) -> usize {
+    (k..n).fold(k, |p, i| {
+        if values[k * n + i].magnitude() > values[k * n + p].magnitude() {
+            i
+        } else {
+            p
+        }
+    })
+}
+
+fn square<T: Element>(matrix: &Conventional<T>) -> Result<()> {
+    let &Conventional { rows, columns, .. } = matrix;
+    if rows != columns {
+        raise!(format!(
+            "the matrix should be square but it is {} × {}",
+            rows, columns
+        ));
+    }
+    Ok(())
+}
+
+fn swap<T: Element>(values: &mut [T], n: usize, i: usize, k: usize) {
+    if i != k {
+        for j in 0..n {
+            values.swap(j * n + i, j * n + k);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use assert;
+
+    use c64;
+    use prelude::*;
+
+    #[test]
+    fn determinant() {
+        let matrix = Conventional::from_vec(
+            3,
+            matrix![
+                0.0, 2.0, 1.0;
+                1.0, 3.0, 2.0;
+                4.0, 1.0, 5.0;
+            ],
+        );
+        assert::close(matrix.determinant().unwrap(), -5.0, 1e-14);
+        let (sign, logarithm) = matrix.log_abs_determinant().unwrap();
+        assert_eq!(sign, -1.0);
+        assert::close(logarithm, 5f64.ln(), 1e-14);
+
+        let matrix = Conventional::from_vec(2, vec![1.0, 2.0, 2.0, 4.0]);
+        assert_eq!(matrix.determinant().unwrap(), 0.0);
+        assert_eq!(
+            matrix.log_abs_determinant().unwrap(),
+            (0.0, f64::NEG_INFINITY)
+        );
+        assert!(Conventional::<f64>::new((2, 3)).determinant().is_err());
+    }
+
+    #[test]
+    fn determinant_complex() {
+        let matrix = Conventional::from_vec(
+            2,
+            matrix![
+                c64::new(0.0, 1.0), c64::new(2.0, 0.0);
+                c64::new(1.0, 0.0), c64::new(0.0, 1.0);
+            ],
+        );
+        let determinant = matrix.determinant().unwrap();
+        assert::close(determinant.re, -3.0, 1e-14);
+        assert::close(determinant.im, 0.0, 1e-14);
+        let (sign, logarithm) = matrix.log_abs_determinant().unwrap();
+        assert::close(sign.re, -1.0, 1e-14);
+        assert::close(sign.im, 0.0, 1e-14);
+        assert::close(logarithm, 3f64.ln(), 1e-14);
+    }
+
+    #[test]
+    fn determinant_integer() {
+        let matrix = Conventional::from_vec(
+            4,
+            matrix![
+                 3,  2, -1,  4;
+                 2,  1,  5,  7;
+                 0,  5,  2, -6;
+                -1,  2,  1,  0i64;
+            ],
+        );
+        assert_eq!(matrix.determinant().unwrap(), -418);
+        assert_eq!(matrix.log_abs_determinant().unwrap().0, -1);
+
+        let matrix = Conventional::from_vec(2, vec![2i32, 4, 1, 2]);
+        assert_eq!(matrix.determinant().unwrap(), 0);
+    }
+
+    #[test]
+    fn inverse() {
+        let matrix = Conventional::from_vec(
+            2,
+            matrix![
+                4.0, 7.0;
+                2.0, 6.0;
+            ],
+        );
+        assert::close(
+            &*matrix.inverse().unwrap(),
+            &*Conventional::from_vec(
+                2,
+                matrix![
+                     0.6, -0.7;
+                    -0.2,  0.4;
+                ],
+            ),
+            1e-14,
+        );
+        assert!(Conventional::from_vec(2, vec![1.0, 2.0, 2.0, 4.0])
+            .inverse()
+            .is_err());
+    }
+
+    #[test]
+    fn inverse_integer() {
+        let matrix = Conventional::from_vec(
+            3,
+            matrix![
+                1, 2, 3;
+                0, 1, 4;
+                5, 6, 0i64;
+            ],
+        );
+        assert_eq!(
+            matrix.inverse().unwrap(),
+            Conventional::from_vec(
+                3,
+                matrix![
+                    -24,  18,  5;
+                     20, -15, -4;
+                     -5,   4,  1;
+                ],
+            )
+        );
+        let matrix = Conventional::from_vec(2, vec![0, 1, 2, 0]);
+        assert!(matrix.inverse().is_err());
+        let matrix = Conventional::from_vec(2, vec![0, 1, 1, 0]);
+        assert_eq!(matrix.inverse().unwrap(), matrix);
+    }
+}
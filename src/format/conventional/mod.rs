@@ -5,7 +5,8 @@
 use std::ops::{Deref, DerefMut, Index, IndexMut};
 use std::ptr;
 
-use {Element, Matrix, Position, Size};
+use format::product;
+use {Element, Matrix, Position, Result, Size};
 
 /// A conventional matrix.
 #[derive(Clone, Debug, PartialEq)]
@@ -30,8 +31,11 @@ macro_rules! new(
 
 mod convert;
 mod decomposition;
+mod determinant;
 mod operation;
 
+pub use self::determinant::Determinant;
+
 size!(Conventional);
 
 impl<T: Element> Conventional<T> {
@@ -55,6 +59,23 @@ impl<T: Element> Conventional<T> {
         new!(rows, columns, values)
     }
 
+    /// Create a matrix from a vector checking that the vector has exactly
+    /// `rows × columns` elements.
+    pub fn try_from_vec<S: Size>(size: S, values: Vec<T>) -> Result<Self> {
+        let (rows, columns) = size.dimensions();
+        let expected = product(rows, columns)?;
+        if values.len() != expected {
+            raise!(format!(
+                "a {} × {} matrix requires {} values but {} have been given",
+                rows,
+                columns,
+                expected,
+                values.len()
+            ));
+        }
+        Ok(new!(rows, columns, values))
+    }
+
     /// Create a matrix with uninitialized elements.
     pub unsafe fn with_uninitialized<S: Size>(size: S) -> Self {
         let (rows, columns) = size.dimensions();
@@ -241,4 +262,12 @@ mod tests {
         let matrix = Conventional::from_vec(2, vec![1.0, 2.0, 3.0, 0.0]);
         assert_eq!(matrix.nonzeros(), 3);
     }
+
+    #[test]
+    fn try_from_vec() {
+        let matrix = Conventional::try_from_vec((2, 3), vec![1.0; 6]).unwrap();
+        assert_eq!(matrix, Conventional::from_vec((2, 3), vec![1.0; 6]));
+        assert!(Conventional::try_from_vec((2, 3), vec![1.0; 5]).is_err());
+        assert!(Conventional::try_from_vec((usize::max_value(), 2), vec![1.0]).is_err());
+    }
 }
@@ -1,40 +1,27 @@
 use blas as backend;
 
-use format::Conventional;
-use operation::{Multiply, MultiplyInto, ScaleSelf};
+use std::any::TypeId;
+use std::slice;
 
-impl Multiply<[f64], Conventional<f64>> for Conventional<f64> {
-    #[inline]
-    fn multiply(&self, right: &[f64]) -> Self {
-        let (m, p) = (self.rows, self.columns);
-        let n = right.len() / p;
-        let mut result = unsafe { Conventional::with_uninitialized((m, n)) };
-        multiply(1.0, &self.values, right, 0.0, &mut result.values, m, p, n);
-        result
-    }
-}
-
-impl MultiplyInto<Conventional<f64>, [f64]> for Conventional<f64> {
-    #[inline(always)]
-    fn multiply_into(&self, right: &Self, result: &mut [f64]) {
-        MultiplyInto::multiply_into(self, &*right as &[f64], result)
-    }
-}
+use operation::ScaleSelf;
+use Element;
 
-impl MultiplyInto<Vec<f64>, [f64]> for Conventional<f64> {
-    #[inline(always)]
-    fn multiply_into(&self, right: &Vec<f64>, result: &mut [f64]) {
-        MultiplyInto::multiply_into(self, &*right as &[f64], result)
-    }
-}
-
-impl MultiplyInto<[f64], [f64]> for Conventional<f64> {
-    #[inline]
-    fn multiply_into(&self, right: &[f64], result: &mut [f64]) {
-        let (m, p) = (self.rows, self.columns);
-        let n = right.len() / p;
-        multiply(1.0, &self.values, right, 1.0, result, m, p, n)
+pub fn multiply<T>(a: &[T], b: &[T], c: &mut [T], m: usize, p: usize, n: usize) -> bool
+where
+    T: 'static + Element,
+{
+    if TypeId::of::<T>() != TypeId::of::<f64>() {
+        return false;
     }
+    let (a, b, c) = unsafe {
+        (
+            slice::from_raw_parts(a.as_ptr() as *const f64, a.len()),
+            slice::from_raw_parts(b.as_ptr() as *const f64, b.len()),
+            slice::from_raw_parts_mut(c.as_mut_ptr() as *mut f64, c.len()),
+        )
+    };
+    multiply_dense(1.0, a, b, 1.0, c, m, p, n);
+    true
 }
 
 impl ScaleSelf<f64> for [f64] {
@@ -46,7 +33,7 @@ impl ScaleSelf<f64> for [f64] {
     }
 }
 
-fn multiply(
+fn multiply_dense(
     alpha: f64,
     a: &[f64],
     b: &[f64],
@@ -75,45 +62,6 @@ fn multiply(
 mod tests {
     use prelude::*;
 
-    #[test]
-    fn multiply() {
-        let matrix = Conventional::from_vec((2, 3), vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
-        let right = Conventional::from_vec(
-            (3, 4),
-            vec![
-                1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0, 10.0, 11.0, 12.0,
-            ],
-        );
-        assert_eq!(
-            matrix.multiply(&right),
-            Conventional::from_vec(
-                (2, 4),
-                vec![22.0, 28.0, 49.0, 64.0, 76.0, 100.0, 103.0, 136.0]
-            )
-        );
-    }
-
-    #[test]
-    fn multiply_into() {
-        let matrix = Conventional::from_vec((2, 3), vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
-        let right = Conventional::from_vec(
-            (3, 4),
-            vec![
-                1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0, 10.0, 11.0, 12.0,
-            ],
-        );
-        let mut result =
-            Conventional::from_vec((2, 4), vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0]);
-        matrix.multiply_into(&right, &mut result);
-        assert_eq!(
-            result,
-            Conventional::from_vec(
-                (2, 4),
-                vec![23.0, 30.0, 52.0, 68.0, 81.0, 106.0, 110.0, 144.0],
-            )
-        );
-    }
-
     #[test]
     fn scale_self() {
         let mut matrix = Conventional::from_vec(2, vec![21.0, 21.0, 21.0, 21.0]);
@@ -1,10 +1,67 @@
+use format::multiply::Columns;
 use format::{Conventional, Diagonal};
-use operation::{MultiplySelf, Transpose};
+use operation::{Multiply, MultiplyInto, MultiplySelf, Transpose};
 use {Element, Number};
 
 #[cfg(feature = "acceleration")]
 mod acceleration;
 
+impl<T> Multiply<[T], Conventional<T>> for Conventional<T>
+where
+    T: 'static + Element + Number,
+{
+    #[inline]
+    fn multiply(&self, right: &[T]) -> Self {
+        let (m, p) = (self.rows, self.columns);
+        let mut result = Conventional::new((m, right.len() / p));
+        self.multiply_into(right, &mut result.values);
+        result
+    }
+}
+
+impl<T> Multiply<Conventional<T>, Conventional<T>> for Conventional<T>
+where
+    T: 'static + Element + Number,
+{
+    #[inline]
+    fn multiply(&self, right: &Self) -> Self {
+        debug_assert_eq!(self.columns, right.rows);
+        self.multiply(&right.values as &[T])
+    }
+}
+
+impl<T> MultiplyInto<Conventional<T>, [T]> for Conventional<T>
+where
+    T: 'static + Element + Number,
+{
+    #[inline(always)]
+    fn multiply_into(&self, right: &Self, result: &mut [T]) {
+        MultiplyInto::multiply_into(self, &right.values as &[T], result)
+    }
+}
+
+impl<T> MultiplyInto<Vec<T>, [T]> for Conventional<T>
+where
+    T: 'static + Element + Number,
+{
+    #[inline(always)]
+    fn multiply_into(&self, right: &Vec<T>, result: &mut [T]) {
+        MultiplyInto::multiply_into(self, right as &[T], result)
+    }
+}
+
+impl<T> MultiplyInto<[T], [T]> for Conventional<T>
+where
+    T: 'static + Element + Number,
+{
+    #[inline]
+    fn multiply_into(&self, right: &[T], result: &mut [T]) {
+        let (m, p) = (self.rows, self.columns);
+        let n = right.len() / p;
+        multiply(&self.values, right, result, m, p, n)
+    }
+}
+
 impl<T> MultiplySelf<Diagonal<T>> for Conventional<T>
 where
     T: Element + Number,
@@ -23,6 +80,19 @@ where
     }
 }
 
+impl<T: Element> Columns<T> for Conventional<T> {
+    #[inline]
+    fn column<F>(&self, j: usize, mut callback: F)
+    where
+        F: FnMut(usize, T),
+    {
+        let m = self.rows;
+        for (i, &value) in self.values[(j * m)..((j + 1) * m)].iter().enumerate() {
+            callback(i, value);
+        }
+    }
+}
+
 impl<T: Element> Transpose for Conventional<T> {
     fn transpose(&self) -> Self {
         let (rows, columns) = (self.rows, self.columns);
@@ -36,10 +106,76 @@ impl<T: Element> Transpose for Conventional<T> {
     }
 }
 
+fn multiply<T>(a: &[T], b: &[T], c: &mut [T], m: usize, p: usize, n: usize)
+where
+    T: 'static + Element + Number,
+{
+    debug_assert_eq!(a.len(), m * p);
+    debug_assert_eq!(b.len(), p * n);
+    debug_assert_eq!(c.len(), m * n);
+    #[cfg(feature = "acceleration")]
+    {
+        if acceleration::multiply(a, b, c, m, p, n) {
+            return;
+        }
+    }
+    for j in 0..n {
+        let (bo, co) = (j * p, j * m);
+        for l in 0..p {
+            let (factor, ao) = (b[bo + l], l * m);
+            if Element::is_zero(&factor) {
+                continue;
+            }
+            for i in 0..m {
+                c[co + i] = c[co + i] + a[ao + i] * factor;
+            }
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use prelude::*;
 
+    #[test]
+    fn multiply() {
+        let matrix = Conventional::from_vec((2, 3), vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
+        let right = Conventional::from_vec(
+            (3, 4),
+            vec![
+                1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0, 10.0, 11.0, 12.0,
+            ],
+        );
+        assert_eq!(
+            matrix.multiply(&right),
+            Conventional::from_vec(
+                (2, 4),
+                vec![22.0, 28.0, 49.0, 64.0, 76.0, 100.0, 103.0, 136.0]
+            )
+        );
+    }
+
+    #[test]
+    fn multiply_into() {
+        let matrix = Conventional::from_vec((2, 3), vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
+        let right = Conventional::from_vec(
+            (3, 4),
+            vec![
+                1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0, 10.0, 11.0, 12.0,
+            ],
+        );
+        let mut result =
+            Conventional::from_vec((2, 4), vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0]);
+        matrix.multiply_into(&right, &mut result);
+        assert_eq!(
+            result,
+            Conventional::from_vec(
+                (2, 4),
+                vec![23.0, 30.0, 52.0, 68.0, 81.0, 106.0, 110.0, 144.0],
+            )
+        );
+    }
+
     #[test]
     fn multiply_self() {
         let mut matrix = Conventional::from_vec(
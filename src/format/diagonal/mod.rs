@@ -4,7 +4,7 @@
 
 use std::ops::{Deref, DerefMut};
 
-use {Element, Matrix, Size};
+use {Element, Matrix, Result, Size};
 
 /// A diagonal matrix.
 #[derive(Clone, Debug, PartialEq)]
@@ -60,6 +60,23 @@ impl<T: Element> Diagonal<T> {
         new!(rows, columns, values)
     }
 
+    /// Create a matrix from a vector checking that the vector has exactly
+    /// `min(rows, columns)` elements.
+    pub fn try_from_vec<S: Size>(size: S, values: Vec<T>) -> Result<Self> {
+        let (rows, columns) = size.dimensions();
+        let expected = min!(rows, columns);
+        if values.len() != expected {
+            raise!(format!(
+                "a {} × {} diagonal matrix requires {} values but {} have been given",
+                rows,
+                columns,
+                expected,
+                values.len()
+            ));
+        }
+        Ok(new!(rows, columns, values))
+    }
+
     /// Create a matrix with uninitialized elements.
     pub unsafe fn with_uninitialized<S: Size>(size: S) -> Self {
         let (rows, columns) = size.dimensions();
@@ -107,4 +124,12 @@ mod tests {
         let matrix = Diagonal::from_vec(4, vec![1.0, 2.0, 0.0, 3.0]);
         assert_eq!(matrix.nonzeros(), 3);
     }
+
+    #[test]
+    fn try_from_vec() {
+        let matrix = Diagonal::try_from_vec((3, 5), vec![1.0, 2.0, 3.0]).unwrap();
+        assert_eq!(matrix, Diagonal::from_vec((3, 5), vec![1.0, 2.0, 3.0]));
+        assert!(Diagonal::try_from_vec((3, 5), vec![1.0, 2.0]).is_err());
+        assert!(Diagonal::try_from_vec((5, 3), vec![1.0; 5]).is_err());
+    }
 }
@@ -1,6 +1,150 @@
-use format::Diagonal;
-use operation::Transpose;
-use Element;
+use decomposition::Solve;
+use format::multiply::Columns;
+use format::{Compressed, Conventional, Diagonal};
+use operation::{Multiply, Transpose};
+use {Element, Number, Result};
+
+impl<T> Diagonal<T>
+where
+    T: Element + Number,
+{
+    /// Compute the inverse.
+    ///
+    /// The function fails when the matrix is not square or has a zero on the
+    /// diagonal.
+    pub fn inverse(&self) -> Result<Self> {
+        let &Diagonal {
+            rows,
+            columns,
+            ref values,
+        } = validate!(self);
+        if rows != columns {
+            raise!("the matrix should be square");
+        }
+        if let Some(i) = values.iter().position(Element::is_zero) {
+            raise!(format!("the matrix is singular (element {})", i));
+        }
+        let one: T = Element::one();
+        Ok(new!(
+            rows,
+            columns,
+            values.iter().map(|&value| one / value).collect()
+        ))
+    }
+
+    /// Compute the pseudoinverse.
+    ///
+    /// The elements whose magnitude does not exceed `tolerance` are treated as
+    /// zeros.
+    pub fn pseudo_inverse(&self, tolerance: f64) -> Self {
+        let &Diagonal {
+            rows,
+            columns,
+            ref values,
+        } = validate!(self);
+        let one: T = Element::one();
+        new!(
+            columns,
+            rows,
+            values
+                .iter()
+                .map(|&value| if value.magnitude() > tolerance {
+                    one / value
+                } else {
+                    Element::zero()
+                })
+                .collect()
+        )
+    }
+}
+
+impl<T> Multiply<Compressed<T>, Compressed<T>> for Diagonal<T>
+where
+    T: Element + Number,
+{
+    fn multiply(&self, right: &Compressed<T>) -> Compressed<T> {
+        debug_assert_eq!(self.columns, right.rows);
+        let mut result = right.clone();
+        result.resize((self.rows, right.columns));
+        for (i, _, value) in result.iter_mut() {
+            *value = self.values[i] * *value;
+        }
+        result
+    }
+}
+
+impl<T> Multiply<Conventional<T>, Conventional<T>> for Diagonal<T>
+where
+    T: Element + Number,
+{
+    fn multiply(&self, right: &Conventional<T>) -> Conventional<T> {
+        let (m, p, n) = (self.rows, self.columns, right.columns);
+        debug_assert_eq!(p, right.rows);
+        let mut result = Conventional::new((m, n));
+        for j in 0..n {
+            for (i, &value) in self.values.iter().enumerate() {
+                result.values[j * m + i] = value * right.values[j * p + i];
+            }
+        }
+        result
+    }
+}
+
+impl<T> Multiply<Diagonal<T>, Diagonal<T>> for Diagonal<T>
+where
+    T: Element + Number,
+{
+    fn multiply(&self, right: &Diagonal<T>) -> Diagonal<T> {
+        let (m, n) = (self.rows, right.columns);
+        debug_assert_eq!(self.columns, right.rows);
+        let mut result = Diagonal::new((m, n));
+        for ((value, &left), &right) in result
+            .values
+            .iter_mut()
+            .zip(&self.values)
+            .zip(&right.values)
+        {
+            *value = left * right;
+        }
+        result
+    }
+}
+
+impl<T: Element> Columns<T> for Diagonal<T> {
+    #[inline]
+    fn column<F>(&self, j: usize, mut callback: F)
+    where
+        F: FnMut(usize, T),
+    {
+        if j < self.values.len() {
+            callback(j, self.values[j]);
+        }
+    }
+}
+
+impl<T> Solve<T> for Diagonal<T>
+where
+    T: Element + Number,
+{
+    fn solve(&self, right: &mut [T]) {
+        let n = self.values.len();
+        debug_assert_eq!(self.rows, self.columns);
+        if n == 0 {
+            return;
+        }
+        debug_assert_eq!(right.len() % n, 0);
+        for b in right.chunks_mut(n) {
+            for (value, &diagonal) in b.iter_mut().zip(&self.values) {
+                *value = *value / diagonal;
+            }
+        }
+    }
+
+    #[inline]
+    fn solve_transpose(&self, right: &mut [T]) {
+        self.solve(right)
+    }
+}
 
 impl<T: Element> Transpose for Diagonal<T> {
     #[inline(always)]
@@ -8,3 +152,104 @@ impl<T: Element> Transpose for Diagonal<T> {
         self.clone()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use format::compressed::Variant;
+    use prelude::*;
+
+    #[test]
+    fn inverse() {
+        let matrix = Diagonal::from_vec(3, vec![2.0, 4.0, -8.0]);
+        assert_eq!(
+            matrix.inverse().unwrap(),
+            Diagonal::from_vec(3, vec![0.5, 0.25, -0.125])
+        );
+        assert!(Diagonal::from_vec(2, vec![1.0, 0.0]).inverse().is_err());
+        assert!(Diagonal::from_vec((2, 3), vec![1.0, 2.0])
+            .inverse()
+            .is_err());
+    }
+
+    #[test]
+    fn multiply_compressed() {
+        let left = Diagonal::from_vec((4, 3), vec![1.0, 2.0, 3.0]);
+        let right = Compressed::from(Conventional::from_vec(
+            (3, 2),
+            matrix![
+                1.0, 0.0;
+                0.0, 2.0;
+                3.0, 4.0;
+            ],
+        ));
+        assert_eq!(right.variant, Variant::Column);
+        assert_eq!(
+            Conventional::from(left.multiply(&right)),
+            Conventional::from_vec(
+                (4, 2),
+                matrix![
+                    1.0,  0.0;
+                    0.0,  4.0;
+                    9.0, 12.0;
+                    0.0,  0.0;
+                ],
+            )
+        );
+    }
+
+    #[test]
+    fn multiply_conventional() {
+        let left = Diagonal::from_vec((2, 3), vec![2.0, 3.0]);
+        let right = Conventional::from_vec(
+            (3, 2),
+            matrix![
+                1.0, 4.0;
+                2.0, 5.0;
+                3.0, 6.0;
+            ],
+        );
+        assert_eq!(
+            left.multiply(&right),
+            Conventional::from_vec(
+                2,
+                matrix![
+                    2.0,  8.0;
+                    6.0, 15.0;
+                ],
+            )
+        );
+    }
+
+    #[test]
+    fn multiply_diagonal() {
+        let left = Diagonal::from_vec((2, 4), vec![2.0, 3.0]);
+        let right = Diagonal::from_vec((4, 3), vec![4.0, 5.0, 6.0]);
+        assert_eq!(
+            left.multiply(&right),
+            Diagonal::from_vec((2, 3), vec![8.0, 15.0])
+        );
+        let left = Diagonal::from_vec((3, 1), vec![2.0]);
+        let right = Diagonal::from_vec((1, 3), vec![4.0]);
+        assert_eq!(
+            left.multiply(&right),
+            Diagonal::from_vec(3, vec![8.0, 0.0, 0.0])
+        );
+    }
+
+    #[test]
+    fn pseudo_inverse() {
+        let matrix = Diagonal::from_vec((2, 4), vec![4.0, 1e-12]);
+        assert_eq!(
+            matrix.pseudo_inverse(1e-10),
+            Diagonal::from_vec((4, 2), vec![0.25, 0.0])
+        );
+    }
+
+    #[test]
+    fn solve() {
+        let matrix = Diagonal::from_vec(2, vec![2.0, 4.0]);
+        let mut right = vec![2.0, 8.0, 1.0, -4.0];
+        matrix.solve(&mut right);
+        assert_eq!(&right, &[1.0, 2.0, 0.5, -1.0]);
+    }
+}
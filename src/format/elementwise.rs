@@ -0,0 +1,686 @@
+//! Element-wise operations.
+//!
+//! The operations visit the stored elements of a matrix, that is, the ones
+//! permitted by its structure, and the structure is kept intact. Implicit
+//! zeros are never visited, so the functions passed to the operations are
+//! expected to map zero to zero.
+
+use format::compressed::Variant;
+use format::packed::Kind;
+use format::{Banded, Compressed, Conventional, Diagonal, Packed};
+use {Element, Number, Result, Size};
+
+/// The pattern of the result of an element-wise operation on two matrices.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Pattern {
+    /// The elements stored in both operands.
+    Intersection,
+    /// The elements stored in at least one of the operands.
+    Union,
+}
+
+// A matrix whose stored elements can be traversed.
+trait Traverse<T> {
+    fn traverse<F>(&mut self, callback: F)
+    where
+        F: FnMut(usize, usize, &mut T);
+}
+
+macro_rules! elementwise(
+    ($kind:ident) => (
+        impl<T: Element> $kind<T> {
+            /// Combine each row with a vector.
+            ///
+            /// The function receives an element and the element of the vector
+            /// corresponding to its column.
+            pub fn broadcast_row<F>(&mut self, row: &[T], mut combine: F) -> Result<()>
+            where
+                F: FnMut(T, T) -> T,
+            {
+                broadcastable(self, row.len(), self.columns())?;
+                self.traverse(|_, j, value| *value = combine(*value, row[j]));
+                Ok(())
+            }
+
+            /// Combine each column with a vector.
+            ///
+            /// The function receives an element and the element of the vector
+            /// corresponding to its row.
+            pub fn broadcast_column<F>(&mut self, column: &[T], mut combine: F) -> Result<()>
+            where
+                F: FnMut(T, T) -> T,
+            {
+                broadcastable(self, column.len(), self.rows())?;
+                self.traverse(|i, _, value| *value = combine(*value, column[i]));
+                Ok(())
+            }
+
+            /// Compute the Hadamard product, which is the element-wise product.
+            #[inline]
+            pub fn hadamard(&self, other: &Self) -> Result<Self>
+            where
+                T: Number,
+            {
+                self.zip_with(other, Pattern::Intersection, |a, b| a * b)
+            }
+
+            /// Perform the element-wise division.
+            ///
+            /// The elements that are zero in both operands are left zero.
+            #[inline]
+            pub fn divide(&self, other: &Self) -> Result<Self>
+            where
+                T: Number,
+            {
+                self.zip_with(other, Pattern::Union, |a, b| {
+                    if Element::is_zero(&a) && Element::is_zero(&b) {
+                        a
+                    } else {
+                        a / b
+                    }
+                })
+            }
+        }
+    );
+);
+
+macro_rules! map_inplace(
+    ($kind:ident) => (
+        impl<T: Element> $kind<T> {
+            /// Apply a function to the stored elements in place.
+            pub fn map_inplace<F>(&mut self, mut map: F)
+            where
+                F: FnMut(T) -> T,
+            {
+                self.traverse(|_, _, value| *value = map(*value));
+            }
+        }
+    );
+);
+
+elementwise!(Banded);
+elementwise!(Compressed);
+elementwise!(Conventional);
+elementwise!(Diagonal);
+elementwise!(Packed);
+
+map_inplace!(Banded);
+map_inplace!(Compressed);
+map_inplace!(Conventional);
+map_inplace!(Diagonal);
+
+impl<T: Element> Banded<T> {
+    /// Apply a function to the stored elements.
+    pub fn map<U, F>(&self, mut map: F) -> Banded<U>
+    where
+        U: Element,
+        F: FnMut(T) -> U,
+    {
+        Banded {
+            rows: self.rows,
+            columns: self.columns,
+            superdiagonals: self.superdiagonals,
+            subdiagonals: self.subdiagonals,
+            values: self.values.iter().map(|&value| map(value)).collect(),
+        }
+    }
+
+    /// Combine two matrices element by element.
+    ///
+    /// The band of the result is the wider of the two bands when `pattern =
+    /// Union` and the narrower one when `pattern = Intersection`.
+    pub fn zip_with<F>(&self, other: &Self, pattern: Pattern, mut combine: F) -> Result<Self>
+    where
+        F: FnMut(T, T) -> T,
+    {
+        compatible(self, other)?;
+        let (superdiagonals, subdiagonals) = match pattern {
+            Pattern::Intersection => (
+                min!(self.superdiagonals, other.superdiagonals),
+                min!(self.subdiagonals, other.subdiagonals),
+            ),
+            Pattern::Union => (
+                max!(self.superdiagonals, other.superdiagonals),
+                max!(self.subdiagonals, other.subdiagonals),
+            ),
+        };
+        let mut result = Banded::new((self.rows, self.columns), superdiagonals, subdiagonals);
+        for (i, j, value) in result.iter_mut() {
+            *value = combine(self.get((i, j)), other.get((i, j)));
+        }
+        Ok(result)
+    }
+}
+
+impl<T: Element> Compressed<T> {
+    /// Apply a function to the stored elements.
+    ///
+    /// The pattern of the matrix is kept, including explicitly stored zeros.
+    pub fn map<U, F>(&self, mut map: F) -> Compressed<U>
+    where
+        U: Element,
+        F: FnMut(T) -> U,
+    {
+        Compressed {
+            rows: self.rows,
+            columns: self.columns,
+            nonzeros: self.nonzeros,
+            variant: self.variant,
+            values: self.values.iter().map(|&value| map(value)).collect(),
+            indices: self.indices.clone(),
+            offsets: self.offsets.clone(),
+        }
+    }
+
+    /// Apply a zero-preserving function to the nonzero elements.
+    ///
+    /// Explicitly stored zeros are skipped, and the elements mapped to zero
+    /// are removed from the pattern.
+    pub fn map_nonzeros<F>(&self, mut map: F) -> Self
+    where
+        F: FnMut(T) -> T,
+    {
+        let elements = self
+            .iter()
+            .filter(|&(_, _, value)| !value.is_zero())
+            .map(|(i, j, &value)| (i, j, map(value)))
+            .filter(|&(_, _, value)| !value.is_zero())
+            .collect();
+        Compressed::assemble((self.rows, self.columns), self.variant, elements)
+    }
+
+    /// Combine two matrices element by element.
+    ///
+    /// The result has the variant of `self`. When `pattern = Union`, the
+    /// elements missing in one of the operands are passed as zeros.
+    pub fn zip_with<F>(&self, other: &Self, pattern: Pattern, mut combine: F) -> Result<Self>
+    where
+        F: FnMut(T, T) -> T,
+    {
+        compatible(self, other)?;
+        let variant = self.variant;
+        let key = |&(i, j, _): &(usize, usize, T)| match variant {
+            Variant::Column => (j, i),
+            Variant::Row => (i, j),
+        };
+        let left = self
+            .iter()
+            .map(|(i, j, &value)| (i, j, value))
+            .collect::<Vec<_>>();
+        let mut right = other
+            .iter()
+            .map(|(i, j, &value)| (i, j, value))
+            .collect::<Vec<_>>();
+        if other.variant != variant {
+            right.sort_by_key(&key);
+        }
+        let zero = T::zero();
+        let mut elements = Vec::with_capacity(left.len() + right.len());
+        let (mut k, mut l) = (0, 0);
+        while k < left.len() || l < right.len() {
+            if l == right.len() || (k < left.len() && key(&left[k]) < key(&right[l])) {
+                if let Pattern::Union = pattern {
+                    let (i, j, value) = left[k];
+                    elements.push((i, j, combine(value, zero)));
+                }
+                k += 1;
+            } else if k == left.len() || key(&right[l]) < key(&left[k]) {
+                if let Pattern::Union = pattern {
+                    let (i, j, value) = right[l];
+                    elements.push((i, j, combine(zero, value)));
+                }
+                l += 1;
+            } else {
+                let (i, j, value) = left[k];
+                elements.push((i, j, combine(value, right[l].2)));
+                k += 1;
+                l += 1;
+            }
+        }
+        Ok(Compressed::assemble(
+            (self.rows, self.columns),
+            variant,
+            elements,
+        ))
+    }
+}
+
+impl<T: Element> Conventional<T> {
+    /// Apply a function to the elements.
+    pub fn map<U, F>(&self, mut map: F) -> Conventional<U>
+    where
+        U: Element,
+        F: FnMut(T) -> U,
+    {
+        Conventional {
+            rows: self.rows,
+            columns: self.columns,
+            values: self.values.iter().map(|&value| map(value)).collect(),
+        }
+    }
+
+    /// Combine two matrices element by element.
+    ///
+    /// The pattern is irrelevant as all elements are stored.
+    pub fn zip_with<F>(&self, other: &Self, _: Pattern, mut combine: F) -> Result<Self>
+    where
+        F: FnMut(T, T) -> T,
+    {
+        compatible(self, other)?;
+        Ok(Conventional {
+            rows: self.rows,
+            columns: self.columns,
+            values: zip(&self.values, &other.values, &mut combine),
+        })
+    }
+}
+
+impl<T: Element> Diagonal<T> {
+    /// Apply a function to the diagonal elements.
+    pub fn map<U, F>(&self, mut map: F) -> Diagonal<U>
+    where
+        U: Element,
+        F: FnMut(T) -> U,
+    {
+        Diagonal {
+            rows: self.rows,
+            columns: self.columns,
+            values: self.values.iter().map(|&value| map(value)).collect(),
+        }
+    }
+
+    /// Combine two matrices element by element.
+    ///
+    /// The pattern is irrelevant as the diagonals of the operands coincide.
+    pub fn zip_with<F>(&self, other: &Self, _: Pattern, mut combine: F) -> Result<Self>
+    where
+        F: FnMut(T, T) -> T,
+    {
+        compatible(self, other)?;
+        Ok(Diagonal {
+            rows: self.rows,
+            columns: self.columns,
+            values: zip(&self.values, &other.values, &mut combine),
+        })
+    }
+}
+
+impl<T: Element> Packed<T> {
+    /// Apply a function to the stored elements.
+    ///
+    /// A unit triangular matrix becomes triangular with the function applied
+    /// to its unit diagonal. The function fails when the matrix is Hermitian
+    /// and the function does not commute with the conjugation, since the
+    /// result would not be Hermitian.
+    pub fn map<U, F>(&self, mut map: F) -> Result<Packed<U>>
+    where
+        U: Element,
+        F: FnMut(T) -> U,
+    {
+        let unit = self.kind == Kind::UnitTriangular;
+        let hermitian = self.kind == Kind::Hermitian;
+        let mut values = Vec::with_capacity(self.values.len());
+        for (i, j, &value) in self.iter() {
+            if i == j && unit {
+                values.push(map(T::one()));
+                continue;
+            }
+            let mapped = map(value);
+            if hermitian {
+                conjugate(i, j, mapped, map(value.conj()))?;
+            }
+            values.push(mapped);
+        }
+        Ok(Packed {
+            size: self.size,
+            variant: self.variant,
+            kind: if unit { Kind::Triangular } else { self.kind },
+            values,
+        })
+    }
+
+    /// Apply a function to the stored elements in place.
+    ///
+    /// The function fails under the same conditions as `map`, in which case
+    /// the matrix is left intact.
+    pub fn map_inplace<F>(&mut self, map: F) -> Result<()>
+    where
+        F: FnMut(T) -> T,
+    {
+        *self = self.map(map)?;
+        Ok(())
+    }
+
+    /// Combine two matrices element by element.
+    ///
+    /// The matrices should be both symmetric, both Hermitian, or both
+    /// triangular with the same variant. For Hermitian matrices, the function
+    /// should commute with the conjugation. The pattern is irrelevant as the
+    /// whole triangle is stored.
+    pub fn zip_with<F>(&self, other: &Self, _: Pattern, mut combine: F) -> Result<Self>
+    where
+        F: FnMut(T, T) -> T,
+    {
+        compatible(self, other)?;
+        let compatible = match (self.kind, other.kind) {
+            (Kind::Symmetric, Kind::Symmetric) | (Kind::Hermitian, Kind::Hermitian) => true,
+            (Kind::Symmetric, _) | (Kind::Hermitian, _) => false,
+            (_, Kind::Symmetric) | (_, Kind::Hermitian) => false,
+            _ => self.variant == other.variant,
+        };
+        if !compatible {
+            raise!(format!(
+                "cannot combine a {:?} {:?} matrix with a {:?} {:?} matrix",
+                self.variant, self.kind, other.variant, other.kind
+            ));
+        }
+        let hermitian = self.kind == Kind::Hermitian;
+        let mut result = self.clone();
+        result.materialize();
+        for (i, j, value) in result.iter_mut() {
+            let (left, right) = (*value, other.get((i, j)));
+            *value = combine(left, right);
+            if hermitian {
+                conjugate(i, j, *value, combine(left.conj(), right.conj()))?;
+            }
+        }
+        Ok(result)
+    }
+}
+
+impl<T: Element> Traverse<T> for Banded<T> {
+    #[inline]
+    fn traverse<F>(&mut self, mut callback: F)
+    where
+        F: FnMut(usize, usize, &mut T),
+    {
+        for (i, j, value) in self.iter_mut() {
+            callback(i, j, value);
+        }
+    }
+}
+
+impl<T: Element> Traverse<T> for Compressed<T> {
+    #[inline]
+    fn traverse<F>(&mut self, mut callback: F)
+    where
+        F: FnMut(usize, usize, &mut T),
+    {
+        for (i, j, value) in self.iter_mut() {
+            callback(i, j, value);
+        }
+    }
+}
+
+impl<T: Element> Traverse<T> for Conventional<T> {
+    fn traverse<F>(&mut self, mut callback: F)
+    where
+        F: FnMut(usize, usize, &mut T),
+    {
+        let rows = self.rows;
+        for (k, value) in self.values.iter_mut().enumerate() {
+            callback(k % rows, k / rows, value);
+        }
+    }
+}
+
+impl<T: Element> Traverse<T> for Diagonal<T> {
+    #[inline]
+    fn traverse<F>(&mut self, mut callback: F)
+    where
+        F: FnMut(usize, usize, &mut T),
+    {
+        for (i, value) in self.values.iter_mut().enumerate() {
+            callback(i, i, value);
+        }
+    }
+}
+
+impl<T: Element> Traverse<T> for Packed<T> {
+    // The elements of symmetric and Hermitian matrices are visited once for
+    // both positions they represent.
+    #[inline]
+    fn traverse<F>(&mut self, mut callback: F)
+    where
+        F: FnMut(usize, usize, &mut T),
+    {
+        self.materialize();
+        for (i, j, value) in self.iter_mut() {
+            callback(i, j, value);
+        }
+    }
+}
+
+fn broadcastable<M: Broadcastable>(matrix: &M, length: usize, expected: usize) -> Result<()> {
+    if !matrix.broadcastable() {
+        raise!("broadcasting is not supported for symmetric and Hermitian matrices");
+    }
+    if length != expected {
+        raise!(format!(
+            "the vector should have {} elements but has {}",
+            expected, length
+        ));
+    }
+    Ok(())
+}
+
+fn compatible<M: Size>(left: &M, right: &M) -> Result<()> {
+    let ((m, n), (p, q)) = (left.dimensions(), right.dimensions());
+    if m != p || n != q {
+        raise!(format!(
+            "the dimensions of the operands do not match ({} × {} and {} × {})",
+            m, n, p, q
+        ));
+    }
+    Ok(())
+}
+
+// Check that the image of the conjugate of an element is the conjugate of the
+// image of the element, which keeps a Hermitian matrix Hermitian.
+fn conjugate<T: Element>(i: usize, j: usize, value: T, mirror: T) -> Result<()> {
+    if mirror != value.conj() {
+        raise!(format!(
+            "the function does not preserve the Hermitian structure at ({}, {})",
+            i, j
+        ));
+    }
+    Ok(())
+}
+
+fn zip<T, F>(left: &[T], right: &[T], combine: &mut F) -> Vec<T>
+where
+    T: Element,
+    F: FnMut(T, T) -> T,
+{
+    left.iter()
+        .zip(right)
+        .map(|(&left, &right)| combine(left, right))
+        .collect()
+}
+
+// A matrix that can be combined with vectors row or column wise.
+trait Broadcastable: Size {
+    #[inline]
+    fn broadcastable(&self) -> bool {
+        true
+    }
+}
+
+impl<T: Element> Broadcastable for Banded<T> {}
+
+impl<T: Element> Broadcastable for Compressed<T> {}
+
+impl<T: Element> Broadcastable for Conventional<T> {}
+
+impl<T: Element> Broadcastable for Diagonal<T> {}
+
+impl<T: Element> Broadcastable for Packed<T> {
+    #[inline]
+    fn broadcastable(&self) -> bool {
+        self.kind != Kind::Symmetric && self.kind != Kind::Hermitian
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use c64;
+    use format::compressed::Variant;
+    use format::packed::{self, Kind};
+    use format::Pattern;
+    use prelude::*;
+
+    #[test]
+    fn broadcast() {
+        let mut matrix = Conventional::from_vec(
+            2,
+            matrix![
+                1.0, 2.0;
+                3.0, 4.0;
+            ],
+        );
+        matrix.broadcast_row(&[10.0, 100.0], |a, b| a * b).unwrap();
+        matrix.broadcast_column(&[1.0, -1.0], |a, b| a + b).unwrap();
+        assert_eq!(&matrix.values, &[11.0, 29.0, 201.0, 399.0]);
+        assert!(matrix.broadcast_row(&[1.0], |a, b| a * b).is_err());
+
+        let mut matrix = Diagonal::from_vec((2, 3), vec![1.0, 2.0]);
+        matrix
+            .broadcast_row(&[2.0, 3.0, 4.0], |a, b| a * b)
+            .unwrap();
+        assert_eq!(&matrix.values, &[2.0, 6.0]);
+
+        let mut matrix = Packed::from_parts(
+            2,
+            packed::Variant::Lower,
+            Kind::Symmetric,
+            vec![1.0, 2.0, 3.0],
+        )
+        .unwrap();
+        assert!(matrix.broadcast_column(&[1.0, 2.0], |a, b| a * b).is_err());
+    }
+
+    #[test]
+    fn hadamard() {
+        let left = Banded::from(Conventional::from_vec(
+            3,
+            matrix![
+                1.0, 2.0, 0.0;
+                3.0, 4.0, 5.0;
+                0.0, 6.0, 7.0;
+            ],
+        ));
+        let right = Banded::from(Diagonal::from_vec(3, vec![2.0, 3.0, 4.0]));
+        let product = left.hadamard(&right).unwrap();
+        assert_eq!((product.superdiagonals, product.subdiagonals), (0, 0));
+        assert_eq!(&product.values, &[2.0, 12.0, 28.0]);
+        let quotient = right.divide(&left).unwrap();
+        assert_eq!((quotient.superdiagonals, quotient.subdiagonals), (1, 1));
+        assert_eq!(quotient.get((1, 1)), 0.75);
+        assert_eq!(quotient.get((1, 0)), 0.0);
+        assert!(left.hadamard(&Banded::new(2, 0, 0)).is_err());
+    }
+
+    #[test]
+    fn divide() {
+        let matrix = Conventional::from(Diagonal::from_vec(2, vec![1.0, 2.0]));
+        let quotient = matrix.divide(&matrix).unwrap();
+        assert_eq!(&quotient.values, &[1.0, 0.0, 0.0, 1.0]);
+
+        let mut left = Banded::new(2, 1, 0);
+        let mut right = Banded::new(2, 0, 1);
+        left.set((1, 1), 2.0);
+        right.set((1, 1), 4.0);
+        let quotient = left.divide(&right).unwrap();
+        assert_eq!(&Conventional::from(quotient).values, &[0.0, 0.0, 0.0, 0.5]);
+    }
+
+    #[test]
+    fn hermitian() {
+        let mut matrix = Packed::from_parts(
+            2,
+            packed::Variant::Lower,
+            Kind::Hermitian,
+            vec![c64::new(1.0, 0.0), c64::new(2.0, 3.0), c64::new(4.0, 0.0)],
+        )
+        .unwrap();
+        let mapped = matrix.map(|value| value * 2.0).unwrap();
+        assert_eq!(mapped.kind, Kind::Hermitian);
+        assert_eq!(mapped.values[1], c64::new(4.0, 6.0));
+        assert!(matrix.map(|value| value * c64::new(0.0, 1.0)).is_err());
+        assert!(matrix
+            .map_inplace(|value| value * c64::new(0.0, 1.0))
+            .is_err());
+        assert_eq!(matrix.values[1], c64::new(2.0, 3.0));
+        matrix.map_inplace(|value| -value).unwrap();
+        assert_eq!(matrix.values[1], c64::new(-2.0, -3.0));
+
+        let product = matrix.hadamard(&matrix).unwrap();
+        assert_eq!(product.values[0], c64::new(1.0, 0.0));
+        let shifted = matrix.zip_with(&matrix, Pattern::Union, |a, b| a + b + c64::new(0.0, 1.0));
+        assert!(shifted.is_err());
+    }
+
+    #[test]
+    fn map() {
+        let matrix = Conventional::from_vec(2, vec![1.0, -2.0, 3.0, -4.0]);
+        assert_eq!(
+            &matrix.map(|value: f64| value.abs() as i32).values,
+            &[1, 2, 3, 4]
+        );
+
+        let mut matrix = Diagonal::from_vec(2, vec![1.0, 2.0]);
+        matrix.map_inplace(|value| value * value - 1.0);
+        assert_eq!(&matrix.values, &[0.0, 3.0]);
+
+        let matrix = Packed::from_parts(
+            2,
+            packed::Variant::Upper,
+            Kind::UnitTriangular,
+            vec![0.0, 5.0, 0.0],
+        )
+        .unwrap();
+        let matrix = matrix.map(|value| value * 2.0).unwrap();
+        assert_eq!(matrix.kind, Kind::Triangular);
+        assert_eq!(&matrix.values, &[2.0, 10.0, 2.0]);
+    }
+
+    #[test]
+    fn map_nonzeros() {
+        let mut matrix = Compressed::new((2, 3), Variant::Row);
+        matrix.set((0, 1), 1.0);
+        matrix.set((1, 0), 0.0);
+        matrix.set((1, 2), 2.0);
+        let mapped = matrix.map(|value| value - 1.0);
+        assert_eq!(mapped.nonzeros, 3);
+        assert_eq!(&mapped.values, &[0.0, -1.0, 1.0]);
+        let mapped = matrix.map_nonzeros(|value| value - 1.0);
+        assert_eq!(mapped.nonzeros, 1);
+        assert_eq!(mapped.get((1, 2)), 1.0);
+    }
+
+    #[test]
+    fn zip_with() {
+        let mut left = Compressed::new((2, 3), Variant::Column);
+        left.set((0, 0), 1.0);
+        left.set((1, 2), 2.0);
+        let mut right = Compressed::new((2, 3), Variant::Row);
+        right.set((0, 0), 3.0);
+        right.set((0, 1), 4.0);
+        right.set((1, 0), 5.0);
+        let union = left.zip_with(&right, Pattern::Union, |a, b| a + b).unwrap();
+        assert_eq!(union.variant, Variant::Column);
+        assert_eq!(
+            Conventional::from(union),
+            Conventional::from_vec(
+                (2, 3),
+                matrix![
+                    4.0, 4.0, 0.0;
+                    5.0, 0.0, 2.0;
+                ],
+            )
+        );
+        let intersection = left.hadamard(&right).unwrap();
+        assert_eq!(intersection.nonzeros, 1);
+        assert_eq!(intersection.get((0, 0)), 3.0);
+    }
+}
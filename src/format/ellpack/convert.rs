@@ -0,0 +1,228 @@
+use format::compressed::Variant;
+use format::ellpack::{elements, pad, Sliced};
+use format::{Compressed, Conventional, Ellpack};
+use Element;
+
+impl<'l, T: Element> From<&'l Compressed<T>> for Ellpack<T> {
+    fn from(matrix: &'l Compressed<T>) -> Self {
+        let (rows, columns) = (matrix.rows, matrix.columns);
+        let elements = elements(matrix);
+        let width = elements.iter().fold(
+            0,
+            |width, row| {
+                if row.len() > width {
+                    row.len()
+                } else {
+                    width
+                }
+            },
+        );
+        let mut values = vec![T::zero(); rows * width];
+        let mut indices = vec![0; rows * width];
+        for (i, row) in elements.iter().enumerate() {
+            pad(row, width, &mut values, &mut indices, i, rows);
+        }
+        Ellpack {
+            rows,
+            columns,
+            width,
+            values,
+            indices,
+        }
+    }
+}
+
+impl<T: Element> From<Compressed<T>> for Ellpack<T> {
+    #[inline]
+    fn from(matrix: Compressed<T>) -> Self {
+        (&matrix).into()
+    }
+}
+
+impl<'l, T: Element> From<&'l Ellpack<T>> for Compressed<T> {
+    /// Convert into a compressed matrix in the compressed-row variant.
+    ///
+    /// The zero elements, including padding, are skipped.
+    fn from(matrix: &'l Ellpack<T>) -> Self {
+        let &Ellpack {
+            rows,
+            columns,
+            width,
+            ref values,
+            ref indices,
+        } = validate!(matrix);
+        let mut result = Compressed::new((rows, columns), Variant::Row);
+        for i in 0..rows {
+            for l in 0..width {
+                let k = l * rows + i;
+                if !values[k].is_zero() {
+                    result.values.push(values[k]);
+                    result.indices.push(indices[k]);
+                }
+            }
+            result.offsets[i + 1] = result.values.len();
+        }
+        result.nonzeros = result.values.len();
+        result
+    }
+}
+
+impl<T: Element> From<Ellpack<T>> for Compressed<T> {
+    #[inline]
+    fn from(matrix: Ellpack<T>) -> Self {
+        (&matrix).into()
+    }
+}
+
+impl<'l, T: Element> From<&'l Ellpack<T>> for Conventional<T> {
+    fn from(matrix: &'l Ellpack<T>) -> Self {
+        let &Ellpack {
+            rows,
+            columns,
+            ref values,
+            ref indices,
+            ..
+        } = validate!(matrix);
+        let mut result = Conventional::new((rows, columns));
+        for (k, &value) in values.iter().enumerate() {
+            if !value.is_zero() {
+                result.values[indices[k] * rows + k % rows] = value;
+            }
+        }
+        result
+    }
+}
+
+impl<T: Element> From<Ellpack<T>> for Conventional<T> {
+    #[inline]
+    fn from(matrix: Ellpack<T>) -> Self {
+        (&matrix).into()
+    }
+}
+
+impl<'l, T: Element> From<&'l Sliced<T>> for Compressed<T> {
+    /// Convert into a compressed matrix in the compressed-row variant.
+    ///
+    /// The zero elements, including padding, are skipped.
+    fn from(matrix: &'l Sliced<T>) -> Self {
+        let &Sliced {
+            rows,
+            columns,
+            chunk,
+            ref permutation,
+            ref widths,
+            ref offsets,
+            ref values,
+            ref indices,
+            ..
+        } = validate!(matrix);
+        let mut elements = vec![vec![]; rows];
+        for (r, &i) in permutation.iter().enumerate() {
+            let s = r / chunk;
+            for l in 0..widths[s] {
+                let k = offsets[s] + l * chunk + r % chunk;
+                if !values[k].is_zero() {
+                    elements[i].push((indices[k], values[k]));
+                }
+            }
+        }
+        let mut result = Compressed::new((rows, columns), Variant::Row);
+        for (i, row) in elements.into_iter().enumerate() {
+            for (j, value) in row {
+                result.values.push(value);
+                result.indices.push(j);
+            }
+            result.offsets[i + 1] = result.values.len();
+        }
+        result.nonzeros = result.values.len();
+        result
+    }
+}
+
+impl<T: Element> From<Sliced<T>> for Compressed<T> {
+    #[inline]
+    fn from(matrix: Sliced<T>) -> Self {
+        (&matrix).into()
+    }
+}
+
+impl<'l, T: Element> From<&'l Sliced<T>> for Conventional<T> {
+    #[inline]
+    fn from(matrix: &'l Sliced<T>) -> Self {
+        Compressed::from(matrix).into()
+    }
+}
+
+impl<T: Element> From<Sliced<T>> for Conventional<T> {
+    #[inline]
+    fn from(matrix: Sliced<T>) -> Self {
+        (&matrix).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use format::ellpack::Sliced;
+    use prelude::*;
+
+    #[test]
+    fn from_compressed() {
+        let matrix = Compressed::from(Conventional::from_vec(
+            (4, 4),
+            matrix![
+                1.0, 0.0, 0.0, 0.0;
+                2.0, 3.0, 4.0, 0.0;
+                0.0, 0.0, 0.0, 0.0;
+                0.0, 5.0, 0.0, 6.0;
+            ],
+        ));
+        let matrix = Ellpack::from(&matrix);
+        assert_eq!(matrix.width, 3);
+        assert_eq!(
+            matrix.values,
+            vec![1.0, 2.0, 0.0, 5.0, 0.0, 3.0, 0.0, 6.0, 0.0, 4.0, 0.0, 0.0]
+        );
+        assert_eq!(matrix.indices, vec![0, 0, 0, 1, 0, 1, 0, 3, 0, 2, 0, 3]);
+    }
+
+    #[test]
+    fn into_compressed() {
+        let compressed = Compressed::from(Conventional::from_vec(
+            (4, 5),
+            matrix![
+                1.0, 0.0, 0.0, 0.0, 0.0;
+                2.0, 3.0, 4.0, 0.0, 0.0;
+                0.0, 0.0, 0.0, 0.0, 0.0;
+                0.0, 5.0, 0.0, 6.0, 7.0;
+            ],
+        ));
+        let expected = Compressed::from(Ellpack::from(&compressed));
+        assert_eq!(expected.variant, compressed.variant.flip());
+        assert_eq!(
+            Conventional::from(&expected),
+            Conventional::from(&compressed)
+        );
+        let matrix = Sliced::from_compressed(&compressed, 2, 2).unwrap();
+        assert_eq!(Compressed::from(&matrix), expected);
+    }
+
+    #[test]
+    fn into_conventional() {
+        let conventional = Conventional::from_vec(
+            (5, 3),
+            matrix![
+                1.0, 0.0, 0.0;
+                2.0, 3.0, 4.0;
+                0.0, 0.0, 0.0;
+                0.0, 5.0, 0.0;
+                0.0, 0.0, 7.0;
+            ],
+        );
+        let compressed = Compressed::from(&conventional);
+        assert_eq!(Conventional::from(Ellpack::from(&compressed)), conventional);
+        for &(chunk, sigma) in &[(1, 1), (2, 1), (2, 5), (4, 2), (8, 8)] {
+            let matrix = Sliced::from_compressed(&compressed, chunk, sigma).unwrap();
+            assert_eq!(Conventional::from(matrix), conventional);
+        }
+    }
+}
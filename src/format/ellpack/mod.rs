@@ -0,0 +1,532 @@
+//! The ELLPACK format.
+//!
+//! The format is suitable for sparse matrices whose rows have nearly the same
+//! number of nonzero elements. The format has two flavors:
+//!
+//! * the [plain][1] one, `Ellpack`, where every row is padded to the length of
+//!   the longest row, and
+//!
+//! * the [sliced][2] one, `Sliced`, also known as SELL-C-σ, where the rows are
+//!   split into slices of `chunk` rows, and every row is padded only to the
+//!   length of the longest row in its slice. In addition, the rows are sorted
+//!   by length within windows of `sigma` rows in order to reduce padding.
+//!
+//! In both cases, the values and indices of a slice are stored such that the
+//! `l`th elements of consecutive rows are adjacent in memory, which makes the
+//! multiplication by a vector amenable to vectorization. Padding elements are
+//! zero and refer to the column of the last stored element of their row or
+//! to the first column when the row is empty.
+//!
+//! [1]: http://netlib.org/linalg/html_templates/node95.html
+//! [2]: https://doi.org/10.1137/130930352
+
+use format::{product, Compressed};
+use {Element, Matrix, Result, Size};
+
+/// An ELLPACK matrix.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Ellpack<T: Element> {
+    /// The number of rows.
+    pub rows: usize,
+    /// The number of columns.
+    pub columns: usize,
+    /// The number of stored elements per row.
+    pub width: usize,
+    /// The values of the stored elements as a `rows × width` matrix stored in
+    /// the column-major order.
+    pub values: Vec<T>,
+    /// The column indices of the stored elements laid out as `values`.
+    pub indices: Vec<usize>,
+}
+
+/// A sliced ELLPACK matrix.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Sliced<T: Element> {
+    /// The number of rows.
+    pub rows: usize,
+    /// The number of columns.
+    pub columns: usize,
+    /// The number of rows per slice.
+    pub chunk: usize,
+    /// The number of rows within which the rows are sorted by length.
+    pub sigma: usize,
+    /// The original indices of the stored rows such that the `i`th stored row
+    /// is the `permutation[i]`th row of the matrix.
+    pub permutation: Vec<usize>,
+    /// The number of stored elements per row of each slice.
+    pub widths: Vec<usize>,
+    /// The offsets of slices such that the values and indices of the `s`th
+    /// slice are stored as a `chunk × widths[s]` matrix in the column-major
+    /// order starting from `values[k]` and `indices[k]`, respectively, where `k
+    /// = offsets[s]`. The vector has one additional element, which is always
+    /// equal to the number of stored elements.
+    pub offsets: Vec<usize>,
+    /// The values of the stored elements.
+    pub values: Vec<T>,
+    /// The column indices of the stored elements.
+    pub indices: Vec<usize>,
+}
+
+mod convert;
+mod operation;
+
+#[cfg(debug_assertions)]
+impl<T: Element> ::format::Validate for Ellpack<T> {
+    fn validate(&self) {
+        assert_eq!(self.rows * self.width, self.values.len());
+        assert_eq!(self.rows * self.width, self.indices.len());
+    }
+}
+
+#[cfg(debug_assertions)]
+impl<T: Element> ::format::Validate for Sliced<T> {
+    fn validate(&self) {
+        let slices = self.slices();
+        assert_eq!(self.rows, self.permutation.len());
+        assert_eq!(slices, self.widths.len());
+        assert_eq!(slices + 1, self.offsets.len());
+        assert_eq!(self.offsets[slices], self.values.len());
+        assert_eq!(self.offsets[slices], self.indices.len());
+    }
+}
+
+size!(Ellpack);
+size!(Sliced);
+
+impl<T: Element> Ellpack<T> {
+    /// Create a zero matrix.
+    pub fn new<S: Size>(size: S) -> Self {
+        let (rows, columns) = size.dimensions();
+        Ellpack {
+            rows,
+            columns,
+            width: 0,
+            values: vec![],
+            indices: vec![],
+        }
+    }
+
+    /// Create a matrix from its parts checking that they are consistent.
+    ///
+    /// The values and indices should form `rows × width` matrices, the column
+    /// indices within each row should be strictly increasing, and the padding
+    /// should be zero and refer to the column of the last stored element of
+    /// its row.
+    pub fn from_parts<S: Size>(
+        size: S,
+        width: usize,
+        values: Vec<T>,
+        indices: Vec<usize>,
+    ) -> Result<Self> {
+        let (rows, columns) = size.dimensions();
+        let expected = product(rows, width)?;
+        if values.len() != expected || indices.len() != expected {
+            raise!(format!(
+                "{} rows of width {} require {} values and indices but {} and {} have been given",
+                rows,
+                width,
+                expected,
+                values.len(),
+                indices.len()
+            ));
+        }
+        for i in 0..rows {
+            check(&values, &indices, (i, rows), width, columns, i)?;
+        }
+        Ok(Ellpack {
+            rows,
+            columns,
+            width,
+            values,
+            indices,
+        })
+    }
+}
+
+impl<T: Element> Sliced<T> {
+    /// Create a zero matrix.
+    pub fn new<S: Size>(size: S, chunk: usize, sigma: usize) -> Self {
+        let (rows, columns) = size.dimensions();
+        debug_assert!(chunk > 0 && sigma > 0);
+        let slices = slices(rows, chunk);
+        Sliced {
+            rows,
+            columns,
+            chunk,
+            sigma,
+            permutation: (0..rows).collect(),
+            widths: vec![0; slices],
+            offsets: vec![0; slices + 1],
+            values: vec![],
+            indices: vec![],
+        }
+    }
+
+    /// Create a zero matrix checking that the chunk and sigma are positive.
+    pub fn try_new<S: Size>(size: S, chunk: usize, sigma: usize) -> Result<Self> {
+        if chunk == 0 || sigma == 0 {
+            raise!("the chunk and sigma should be positive");
+        }
+        Ok(Sliced::new(size, chunk, sigma))
+    }
+
+    /// Create a matrix from its parts checking that they are consistent.
+    ///
+    /// The chunk and sigma should be positive, and the permutation should
+    /// only reorder rows within windows of `sigma` rows. The offsets should
+    /// start with zero and delimit a `chunk × width` matrix of values and
+    /// indices for each slice, which determines the width of the slice, and
+    /// the rows of each slice should satisfy the same conditions as the ones
+    /// of `Ellpack::from_parts`. The rows of the last slice beyond the last
+    /// row of the matrix should be zero.
+    pub fn from_parts<S: Size>(
+        size: S,
+        chunk: usize,
+        sigma: usize,
+        permutation: Vec<usize>,
+        offsets: Vec<usize>,
+        values: Vec<T>,
+        indices: Vec<usize>,
+    ) -> Result<Self> {
+        let (rows, columns) = size.dimensions();
+        if chunk == 0 || sigma == 0 {
+            raise!("the chunk and sigma should be positive");
+        }
+        if permutation.len() != rows {
+            raise!(format!(
+                "the permutation should have {} elements but has {}",
+                rows,
+                permutation.len()
+            ));
+        }
+        for (w, window) in permutation.chunks(sigma).enumerate() {
+            let mut window = window.to_vec();
+            window.sort();
+            if window.iter().enumerate().any(|(r, &i)| i != w * sigma + r) {
+                raise!(format!(
+                    "the permutation should reorder the rows only within window {}",
+                    w
+                ));
+            }
+        }
+        let slices = slices(rows, chunk);
+        if offsets.len() != slices + 1 || offsets[0] != 0 {
+            raise!(format!(
+                "there should be {} offsets starting with 0",
+                slices + 1
+            ));
+        }
+        let mut widths = Vec::with_capacity(slices);
+        for s in 0..slices {
+            let length = offsets[s + 1].wrapping_sub(offsets[s]);
+            if offsets[s + 1] < offsets[s] || length / chunk * chunk != length {
+                raise!(format!(
+                    "the offsets should delimit a multiple of {} values for slice {}",
+                    chunk, s
+                ));
+            }
+            widths.push(length / chunk);
+        }
+        if values.len() != offsets[slices] || indices.len() != offsets[slices] {
+            raise!(format!(
+                "the slices require {} values and indices but {} and {} have been given",
+                offsets[slices],
+                values.len(),
+                indices.len()
+            ));
+        }
+        for s in 0..slices {
+            let (values, indices) = (&values[offsets[s]..], &indices[offsets[s]..]);
+            for r in 0..chunk {
+                let i = s * chunk + r;
+                if i < rows {
+                    check(
+                        values,
+                        indices,
+                        (r, chunk),
+                        widths[s],
+                        columns,
+                        permutation[i],
+                    )?;
+                } else if (0..widths[s]).any(|l| !values[l * chunk + r].is_zero()) {
+                    raise!(format!("the padding rows of slice {} should be zero", s));
+                }
+            }
+        }
+        Ok(Sliced {
+            rows,
+            columns,
+            chunk,
+            sigma,
+            permutation,
+            widths,
+            offsets,
+            values,
+            indices,
+        })
+    }
+
+    /// Create a matrix from a compressed one.
+    ///
+    /// The rows are split into slices of `chunk` rows after sorting them by
+    /// decreasing length within consecutive windows of `sigma` rows.
+    pub fn from_compressed(matrix: &Compressed<T>, chunk: usize, sigma: usize) -> Result<Self> {
+        if chunk == 0 || sigma == 0 {
+            raise!("the chunk and sigma should be positive");
+        }
+        let (rows, columns) = matrix.dimensions();
+        let elements = elements(matrix);
+        let mut permutation = (0..rows).collect::<Vec<_>>();
+        for window in permutation.chunks_mut(sigma) {
+            window.sort_by(|&one, &other| elements[other].len().cmp(&elements[one].len()));
+        }
+        let mut result = Sliced::new((rows, columns), chunk, sigma);
+        for (s, slice) in permutation.chunks(chunk).enumerate() {
+            let width = slice.iter().fold(0, |width, &i| {
+                let length = elements[i].len();
+                if length > width {
+                    length
+                } else {
+                    width
+                }
+            });
+            let offset = result.values.len();
+            result.values.extend(vec![T::zero(); chunk * width]);
+            result.indices.extend(vec![0; chunk * width]);
+            for (r, &i) in slice.iter().enumerate() {
+                pad(
+                    &elements[i],
+                    width,
+                    &mut result.values[offset..],
+                    &mut result.indices[offset..],
+                    r,
+                    chunk,
+                );
+            }
+            result.widths[s] = width;
+            result.offsets[s + 1] = result.values.len();
+        }
+        result.permutation = permutation;
+        Ok(result)
+    }
+
+    /// Return the number of slices.
+    #[inline]
+    pub fn slices(&self) -> usize {
+        slices(self.rows, self.chunk)
+    }
+}
+
+impl<T: Element> Matrix for Ellpack<T> {
+    type Element = T;
+
+    fn nonzeros(&self) -> usize {
+        self.values
+            .iter()
+            .fold(0, |sum, &value| if value.is_zero() { sum } else { sum + 1 })
+    }
+
+    #[inline]
+    fn zero<S: Size>(size: S) -> Self {
+        Ellpack::new(size)
+    }
+}
+
+impl<T: Element> Matrix for Sliced<T> {
+    type Element = T;
+
+    fn nonzeros(&self) -> usize {
+        self.values
+            .iter()
+            .fold(0, |sum, &value| if value.is_zero() { sum } else { sum + 1 })
+    }
+
+    #[inline]
+    fn zero<S: Size>(size: S) -> Self {
+        Sliced::new(size, 1, 1)
+    }
+}
+
+fn slices(rows: usize, chunk: usize) -> usize {
+    let slices = rows / chunk;
+    if slices * chunk < rows {
+        slices + 1
+    } else {
+        slices
+    }
+}
+
+// Check a row stored at `offset` with `stride` elements between its
+// consecutive elements.
+fn check<T: Element>(
+    values: &[T],
+    indices: &[usize],
+    (offset, stride): (usize, usize),
+    width: usize,
+    columns: usize,
+    row: usize,
+) -> Result<()> {
+    let mut padding = false;
+    for l in 0..width {
+        let k = l * stride + offset;
+        if indices[k] >= columns {
+            raise!(format!(
+                "the column index {} of row {} is out of bounds ({})",
+                indices[k], row, columns
+            ));
+        }
+        if l > 0 && indices[k] == indices[k - stride] {
+            if !values[k].is_zero() {
+                raise!(format!(
+                    "the padding of row {} should be zero at position {}",
+                    row, l
+                ));
+            }
+            padding = true;
+        } else if padding || (l > 0 && indices[k] < indices[k - stride]) {
+            raise!(format!(
+                "the column indices of row {} are not strictly increasing at position {}",
+                row, l
+            ));
+        }
+    }
+    Ok(())
+}
+
+fn elements<T: Element>(matrix: &Compressed<T>) -> Vec<Vec<(usize, T)>> {
+    let mut rows = vec![vec![]; matrix.rows];
+    for (i, j, &value) in matrix.iter() {
+        rows[i].push((j, value));
+    }
+    for row in &mut rows {
+        row.sort_by_key(|&(j, _)| j);
+    }
+    rows
+}
+
+fn pad<T: Element>(
+    row: &[(usize, T)],
+    width: usize,
+    values: &mut [T],
+    indices: &mut [usize],
+    offset: usize,
+    stride: usize,
+) {
+    let mut last = 0;
+    for l in 0..width {
+        let k = l * stride + offset;
+        if let Some(&(j, value)) = row.get(l) {
+            values[k] = value;
+            last = j;
+        } else {
+            values[k] = T::zero();
+        }
+        indices[k] = last;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use format::ellpack::Sliced;
+    use format::Ellpack;
+    use prelude::*;
+
+    #[test]
+    fn from_compressed_sliced() {
+        let matrix = Compressed::from(Conventional::from_vec(
+            (5, 4),
+            matrix![
+                1.0, 0.0, 0.0, 0.0;
+                2.0, 3.0, 4.0, 0.0;
+                0.0, 0.0, 0.0, 0.0;
+                0.0, 5.0, 0.0, 6.0;
+                0.0, 0.0, 7.0, 0.0;
+            ],
+        ));
+        let matrix = Sliced::from_compressed(&matrix, 2, 4).unwrap();
+        assert_eq!(matrix.permutation, vec![1, 3, 0, 2, 4]);
+        assert_eq!(matrix.widths, vec![3, 1, 1]);
+        assert_eq!(matrix.offsets, vec![0, 6, 8, 10]);
+        assert_eq!(
+            matrix.values,
+            vec![2.0, 5.0, 3.0, 6.0, 4.0, 0.0, 1.0, 0.0, 7.0, 0.0]
+        );
+        assert_eq!(matrix.indices, vec![0, 1, 1, 3, 2, 3, 0, 0, 2, 0]);
+        assert!(Sliced::from_compressed(&Compressed::<f64>::zero(2), 0, 1).is_err());
+    }
+
+    #[test]
+    fn from_parts() {
+        let matrix = Ellpack::from_parts(
+            (3, 4),
+            2,
+            vec![1.0, 2.0, 0.0, 3.0, 0.0, 0.0],
+            vec![0, 1, 0, 2, 1, 0],
+        )
+        .unwrap();
+        assert_eq!(matrix.nonzeros(), 3);
+        assert!(Ellpack::from_parts((3, 4), 2, vec![1.0; 5], vec![0; 6]).is_err());
+        assert!(Ellpack::from_parts((3, 4), 1, vec![1.0; 3], vec![0, 4, 0]).is_err());
+        assert!(Ellpack::from_parts((1, 4), 2, vec![1.0, 2.0], vec![2, 1]).is_err());
+        assert!(Ellpack::from_parts((1, 4), 2, vec![1.0, 2.0], vec![1, 1]).is_err());
+        assert!(Ellpack::from_parts((1, 4), 3, vec![1.0, 0.0, 2.0], vec![1, 1, 2]).is_err());
+
+        let compressed = Compressed::from(Conventional::from_vec(
+            (3, 4),
+            matrix![
+                1.0, 0.0, 2.0, 0.0;
+                0.0, 0.0, 0.0, 0.0;
+                0.0, 3.0, 0.0, 0.0;
+            ],
+        ));
+        let expected = Sliced::from_compressed(&compressed, 2, 2).unwrap();
+        let matrix = Sliced::from_parts(
+            (3, 4),
+            2,
+            2,
+            expected.permutation.clone(),
+            expected.offsets.clone(),
+            expected.values.clone(),
+            expected.indices.clone(),
+        )
+        .unwrap();
+        assert_eq!(matrix, expected);
+        let parts = |permutation: Vec<usize>, offsets: Vec<usize>, values: Vec<f64>| {
+            Sliced::from_parts(
+                (3, 4),
+                2,
+                2,
+                permutation,
+                offsets,
+                values,
+                vec![0, 0, 2, 0, 1, 0],
+            )
+        };
+        let values = vec![1.0, 0.0, 2.0, 0.0, 3.0, 0.0];
+        assert!(parts(vec![0, 1, 2], vec![0, 4, 6], values.clone()).is_ok());
+        assert!(parts(vec![2, 1, 0], vec![0, 4, 6], values.clone()).is_err());
+        assert!(parts(vec![0, 1, 2], vec![0, 4, 5], values.clone()).is_err());
+        assert!(parts(vec![0, 1, 2], vec![0, 2, 6], values.clone()).is_err());
+        assert!(parts(vec![0, 1, 2], vec![0, 4, 6], vec![1.0; 6]).is_err());
+    }
+
+    #[test]
+    fn try_new() {
+        let matrix = Sliced::<f64>::try_new((5, 4), 2, 4).unwrap();
+        assert_eq!(matrix.widths, vec![0, 0, 0]);
+        assert!(Sliced::<f64>::try_new((5, 4), 0, 4).is_err());
+        assert!(Sliced::<f64>::try_new((5, 4), 2, 0).is_err());
+    }
+
+    #[test]
+    fn nonzeros() {
+        let matrix = Ellpack {
+            rows: 2,
+            columns: 3,
+            width: 2,
+            values: vec![1.0, 2.0, 0.0, 3.0],
+            indices: vec![0, 1, 0, 2],
+        };
+        assert_eq!(matrix.nonzeros(), 3);
+    }
+}
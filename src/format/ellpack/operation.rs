@@ -0,0 +1,177 @@
+use format::compressed::Variant;
+use format::ellpack::Sliced;
+use format::{Compressed, Ellpack};
+use operation::{MultiplyInto, Transpose};
+use {Element, Number};
+
+impl<T> MultiplyInto<[T], [T]> for Ellpack<T>
+where
+    T: Element + Number,
+{
+    #[inline]
+    fn multiply_into(&self, right: &[T], result: &mut [T]) {
+        let (m, p) = (self.rows, self.columns);
+        let n = right.len() / p;
+        multiply_matrix_left(self, right, result, m, p, n)
+    }
+}
+
+impl<T> MultiplyInto<[T], [T]> for Sliced<T>
+where
+    T: Element + Number,
+{
+    #[inline]
+    fn multiply_into(&self, right: &[T], result: &mut [T]) {
+        let (m, p) = (self.rows, self.columns);
+        let n = right.len() / p;
+        multiply_sliced_left(self, right, result, m, p, n)
+    }
+}
+
+impl<T: Element> Transpose for Ellpack<T> {
+    fn transpose(&self) -> Self {
+        Ellpack::from(&transpose(Compressed::from(self)))
+    }
+}
+
+impl<T: Element> Transpose for Sliced<T> {
+    fn transpose(&self) -> Self {
+        let matrix = transpose(Compressed::from(self));
+        Sliced::from_compressed(&matrix, self.chunk, self.sigma).unwrap()
+    }
+}
+
+fn transpose<T: Element>(mut matrix: Compressed<T>) -> Compressed<T> {
+    debug_assert_eq!(matrix.variant, Variant::Row);
+    matrix.variant = Variant::Column;
+    let (rows, columns) = (matrix.rows, matrix.columns);
+    matrix.rows = columns;
+    matrix.columns = rows;
+    matrix
+}
+
+fn multiply_matrix_left<T>(a: &Ellpack<T>, b: &[T], c: &mut [T], m: usize, p: usize, n: usize)
+where
+    T: Element + Number,
+{
+    debug_assert_eq!(a.rows * a.columns, m * p);
+    debug_assert_eq!(b.len(), p * n);
+    debug_assert_eq!(c.len(), m * n);
+    let (values, indices) = (&a.values, &a.indices);
+    if m == 0 {
+        return;
+    }
+    for j in 0..n {
+        let b = &b[(j * p)..((j + 1) * p)];
+        let c = &mut c[(j * m)..((j + 1) * m)];
+        for (values, indices) in values.chunks(m).zip(indices.chunks(m)) {
+            for ((c, &value), &index) in c.iter_mut().zip(values).zip(indices) {
+                *c = *c + value * b[index];
+            }
+        }
+    }
+}
+
+fn multiply_sliced_left<T>(a: &Sliced<T>, b: &[T], c: &mut [T], m: usize, p: usize, n: usize)
+where
+    T: Element + Number,
+{
+    debug_assert_eq!(a.rows * a.columns, m * p);
+    debug_assert_eq!(b.len(), p * n);
+    debug_assert_eq!(c.len(), m * n);
+    let &Sliced {
+        chunk,
+        ref permutation,
+        ref offsets,
+        ref values,
+        ref indices,
+        ..
+    } = a;
+    let mut buffer = vec![Element::zero(); chunk];
+    for j in 0..n {
+        let b = &b[(j * p)..((j + 1) * p)];
+        let c = &mut c[(j * m)..((j + 1) * m)];
+        for (s, rows) in permutation.chunks(chunk).enumerate() {
+            for value in buffer.iter_mut() {
+                *value = Element::zero();
+            }
+            let (start, finish) = (offsets[s], offsets[s + 1]);
+            let values = values[start..finish].chunks(chunk);
+            let indices = indices[start..finish].chunks(chunk);
+            for (values, indices) in values.zip(indices) {
+                for ((sum, &value), &index) in buffer.iter_mut().zip(values).zip(indices) {
+                    *sum = *sum + value * b[index];
+                }
+            }
+            for (&i, &sum) in rows.iter().zip(&buffer) {
+                c[i] = c[i] + sum;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use format::ellpack::Sliced;
+    use prelude::*;
+
+    #[test]
+    fn multiply_into() {
+        let right = Conventional::from_vec(
+            (3, 2),
+            matrix![
+                1.0, 4.0;
+                2.0, 5.0;
+                3.0, 6.0;
+            ],
+        );
+        let expected = matrix![
+             2.0,  5.0;
+            21.0, 48.0;
+             1.0,  1.0;
+            11.0, 26.0;
+            34.0, 73.0;
+        ];
+        let compressed = Compressed::from(Conventional::from_vec(
+            (5, 3),
+            matrix![
+                1.0, 0.0, 0.0;
+                2.0, 3.0, 4.0;
+                0.0, 0.0, 0.0;
+                0.0, 5.0, 0.0;
+                0.0, 6.0, 7.0;
+            ],
+        ));
+        let mut result = Conventional::from_vec((5, 2), vec![1.0; 10]);
+        Ellpack::from(&compressed).multiply_into(&right, &mut result);
+        assert_eq!(&result.values, &expected);
+        for &(chunk, sigma) in &[(1, 1), (2, 4), (4, 4), (8, 1)] {
+            let matrix = Sliced::from_compressed(&compressed, chunk, sigma).unwrap();
+            let mut result = Conventional::from_vec((5, 2), vec![1.0; 10]);
+            matrix.multiply_into(&right, &mut result);
+            assert_eq!(&result.values, &expected);
+        }
+    }
+
+    #[test]
+    fn transpose() {
+        let compressed = Compressed::from(Conventional::from_vec(
+            (5, 3),
+            matrix![
+                1.0, 0.0, 0.0;
+                2.0, 3.0, 4.0;
+                0.0, 0.0, 0.0;
+                0.0, 5.0, 0.0;
+                0.0, 6.0, 7.0;
+            ],
+        ));
+        let expected = Conventional::from(&compressed).transpose();
+        let matrix = Ellpack::from(&compressed).transpose();
+        assert_eq!((matrix.rows, matrix.columns, matrix.width), (3, 5, 3));
+        assert_eq!(Conventional::from(matrix), expected);
+        let matrix = Sliced::from_compressed(&compressed, 2, 2).unwrap();
+        let matrix = matrix.transpose();
+        assert_eq!((matrix.chunk, matrix.sigma), (2, 2));
+        assert_eq!(Conventional::from(matrix), expected);
+    }
+}
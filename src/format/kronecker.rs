@@ -0,0 +1,292 @@
+//! Kronecker products and sums.
+//!
+//! The products and sums involving a sparse operand are assembled column by
+//! column directly in the compressed-column variant. Only the elements that
+//! are nonzero according to the structure of each operand are visited, and
+//! the products that are zero are not stored.
+
+use format;
+use format::compressed::Variant;
+use format::multiply::Columns;
+use format::{Banded, Compressed, Conventional, Diagonal, Packed};
+use operation::{Kronecker, KroneckerSum};
+use {Element, Number, Result};
+
+macro_rules! kronecker_sum(
+    ($left:ident: $($right:ident),*) => ($(
+        impl<T> KroneckerSum<$right<T>, Compressed<T>> for $left<T>
+        where
+            T: Element + Number,
+        {
+            #[inline]
+            fn kronecker_sum(&self, right: &$right<T>) -> Result<Compressed<T>> {
+                sum(self, right)
+            }
+        }
+    )*);
+);
+
+macro_rules! kronecker(
+    ($left:ident: $($right:ident),*) => ($(
+        impl<T> Kronecker<$right<T>, Compressed<T>> for $left<T>
+        where
+            T: Element + Number,
+        {
+            #[inline]
+            fn kronecker(&self, right: &$right<T>) -> Compressed<T> {
+                product(self, right)
+            }
+        }
+    )*);
+);
+
+kronecker_sum!(Banded: Banded, Compressed, Conventional, Diagonal, Packed);
+kronecker_sum!(Compressed: Banded, Compressed, Conventional, Diagonal, Packed);
+kronecker_sum!(Conventional: Banded, Compressed, Conventional, Diagonal, Packed);
+kronecker_sum!(Diagonal: Banded, Compressed, Conventional, Diagonal, Packed);
+kronecker_sum!(Packed: Banded, Compressed, Conventional, Diagonal, Packed);
+
+kronecker!(Banded: Banded, Compressed, Conventional, Diagonal, Packed);
+kronecker!(Compressed: Banded, Compressed, Conventional, Diagonal, Packed);
+kronecker!(Conventional: Banded, Compressed, Diagonal, Packed);
+kronecker!(Diagonal: Banded, Compressed, Conventional, Diagonal, Packed);
+kronecker!(Packed: Banded, Compressed, Conventional, Diagonal, Packed);
+
+impl<T> Kronecker<Conventional<T>, Conventional<T>> for Conventional<T>
+where
+    T: Element + Number,
+{
+    fn kronecker(&self, right: &Conventional<T>) -> Conventional<T> {
+        let (m, n) = (self.rows, self.columns);
+        let (p, q) = (right.rows, right.columns);
+        let (rows, columns) = dimensions((m, n), (p, q));
+        expect(format::product(rows, columns));
+        let mut result = Conventional::new((rows, columns));
+        for j in 0..n {
+            for l in 0..q {
+                let offset = (j * q + l) * m * p;
+                for i in 0..m {
+                    let factor = self.values[j * m + i];
+                    for k in 0..p {
+                        result.values[offset + i * p + k] = factor * right.values[l * p + k];
+                    }
+                }
+            }
+        }
+        result
+    }
+}
+
+fn columns<M, T>(matrix: &M) -> Vec<Vec<(usize, T)>>
+where
+    M: Columns<T>,
+    T: Element,
+{
+    let matrix = matrix.columnar();
+    (0..matrix.columns())
+        .map(|j| {
+            let mut column = vec![];
+            matrix.column(j, |i, value| {
+                if !value.is_zero() {
+                    column.push((i, value));
+                }
+            });
+            column
+        })
+        .collect()
+}
+
+fn product<L, R, T>(left: &L, right: &R) -> Compressed<T>
+where
+    L: Columns<T>,
+    R: Columns<T>,
+    T: Element + Number,
+{
+    let ((m, n), (p, q)) = (left.dimensions(), right.dimensions());
+    let size = dimensions((m, n), (p, q));
+    let (left, right) = (columns(left), columns(right));
+    let mut elements = vec![];
+    for (j, left) in left.iter().enumerate() {
+        for (l, right) in right.iter().enumerate() {
+            for &(i, factor) in left {
+                for &(k, value) in right {
+                    let value = factor * value;
+                    if !Element::is_zero(&value) {
+                        elements.push((i * p + k, j * q + l, value));
+                    }
+                }
+            }
+        }
+    }
+    Compressed::assemble(size, Variant::Column, elements)
+}
+
+// The Kronecker sum of an `m × m` matrix `A` and an `n × n` matrix `B` is
+// `A ⊗ I + I ⊗ B` where the identity matrices are `n × n` and `m × m`,
+// respectively. The `(j n + l)`th column of the result contains `A[i, j]` in
+// the `(i n + l)`th row and `B[k, l]` in the `(j n + k)`th row.
+fn sum<L, R, T>(left: &L, right: &R) -> Result<Compressed<T>>
+where
+    L: Columns<T>,
+    R: Columns<T>,
+    T: Element + Number,
+{
+    let ((m, p), (n, q)) = (left.dimensions(), right.dimensions());
+    if m != p || n != q {
+        raise!(format!(
+            "the Kronecker sum requires square matrices but they are {} × {} and {} × {}",
+            m, p, n, q
+        ));
+    }
+    let size = format::product(m, n)?;
+    let (left, right) = (columns(left), columns(right));
+    let mut elements = vec![];
+    let mut column = vec![];
+    for (j, left) in left.iter().enumerate() {
+        for (l, right) in right.iter().enumerate() {
+            column.clear();
+            column.extend(left.iter().map(|&(i, value)| (i * n + l, value)));
+            column.extend(right.iter().map(|&(k, value)| (j * n + k, value)));
+            column.sort_by_key(|&(i, _)| i);
+            let mut k = 0;
+            while k < column.len() {
+                let (i, mut value) = column[k];
+                k += 1;
+                while k < column.len() && column[k].0 == i {
+                    value = value + column[k].1;
+                    k += 1;
+                }
+                if !Element::is_zero(&value) {
+                    elements.push((i, j * n + l, value));
+                }
+            }
+        }
+    }
+    Ok(Compressed::assemble(
+        (size, size),
+        Variant::Column,
+        elements,
+    ))
+}
+
+// Compute the dimensions of the Kronecker product of an `m × n` and a `p × q`
+// matrix, which are `m p × n q`.
+fn dimensions(left: (usize, usize), right: (usize, usize)) -> (usize, usize) {
+    let rows = expect(format::product(left.0, right.0));
+    let columns = expect(format::product(left.1, right.1));
+    (rows, columns)
+}
+
+fn expect<T>(result: Result<T>) -> T {
+    match result {
+        Ok(result) => result,
+        Err(error) => panic!("the Kronecker product is too large: {}", error),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use format::compressed::Variant;
+    use prelude::*;
+
+    #[test]
+    #[should_panic(expected = "the Kronecker product is too large")]
+    fn kronecker_overflow() {
+        let left = Diagonal::from_vec((usize::max_value() / 2, 1), vec![1.0]);
+        let right = Diagonal::from_vec((usize::max_value() / 2, 1), vec![1.0]);
+        let _: Compressed<_> = left.kronecker(&right);
+    }
+
+    #[test]
+    fn kronecker_compressed() {
+        let mut left = Compressed::new((2, 2), Variant::Row);
+        left.set((0, 1), 2.0);
+        left.set((1, 0), -1.0);
+        let right = Conventional::from_vec(
+            (2, 3),
+            matrix![
+                1.0, 0.0, 3.0;
+                0.0, 4.0, 0.0;
+            ],
+        );
+        let result: Compressed<_> = left.kronecker(&right);
+        assert_eq!(result.variant, Variant::Column);
+        assert_eq!(result.nonzeros, 6);
+        assert_eq!(
+            Conventional::from(result),
+            Conventional::from_vec(
+                (4, 6),
+                matrix![
+                     0.0,  0.0,  0.0, 2.0, 0.0, 6.0;
+                     0.0,  0.0,  0.0, 0.0, 8.0, 0.0;
+                    -1.0,  0.0, -3.0, 0.0, 0.0, 0.0;
+                     0.0, -4.0,  0.0, 0.0, 0.0, 0.0;
+                ],
+            )
+        );
+    }
+
+    #[test]
+    fn kronecker_conventional() {
+        let left = Conventional::from_vec(
+            (1, 2),
+            matrix![
+                1.0, 2.0;
+            ],
+        );
+        let right = Conventional::from_vec(
+            (2, 1),
+            matrix![
+                3.0;
+                4.0;
+            ],
+        );
+        let result: Conventional<_> = left.kronecker(&right);
+        assert_eq!(
+            result,
+            Conventional::from_vec(
+                2,
+                matrix![
+                    3.0, 6.0;
+                    4.0, 8.0;
+                ],
+            )
+        );
+    }
+
+    #[test]
+    fn kronecker_sum() {
+        let matrix = Banded::from(Conventional::from_vec(
+            3,
+            matrix![
+                 2.0, -1.0,  0.0;
+                -1.0,  2.0, -1.0;
+                 0.0, -1.0,  2.0;
+            ],
+        ));
+        let laplacian = matrix.kronecker_sum(&matrix).unwrap();
+        assert_eq!((laplacian.rows, laplacian.columns), (9, 9));
+        assert_eq!(laplacian.nonzeros, 9 + 2 * 12);
+        for i in 0..9 {
+            assert_eq!(laplacian.get((i, i)), 4.0);
+        }
+        assert_eq!(laplacian.get((0, 1)), -1.0);
+        assert_eq!(laplacian.get((0, 3)), -1.0);
+        assert_eq!(laplacian.get((2, 3)), 0.0);
+        assert_eq!(laplacian.get((4, 7)), -1.0);
+
+        let left = Diagonal::from_vec(1, vec![1.0]);
+        let right = Conventional::from_vec(
+            2,
+            matrix![
+                -1.0, 5.0;
+                 0.0, 2.0;
+            ],
+        );
+        let result = left.kronecker_sum(&right).unwrap();
+        assert_eq!(result.nonzeros, 2);
+        assert_eq!(result.get((0, 1)), 5.0);
+        assert_eq!(result.get((1, 1)), 3.0);
+        assert!(left.kronecker_sum(&Conventional::new((2, 3))).is_err());
+    }
+}
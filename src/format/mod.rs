@@ -14,6 +14,13 @@ macro_rules! buffer(
     });
 );
 
+macro_rules! max(
+    ($left:expr, $right:expr) => ({
+        let (left, right) = ($left, $right);
+        if left < right { right } else { left }
+    });
+);
+
 macro_rules! min(
     ($left:expr, $right:expr) => ({
         let (left, right) = ($left, $right);
@@ -55,14 +62,45 @@ macro_rules! validate(
     ($matrix:expr) => ($matrix);
 );
 
+fn sum(left: usize, right: usize) -> ::Result<usize> {
+    if let Some(sum) = left.checked_add(right) {
+        return Ok(sum);
+    }
+    raise!(format!("the sum of {} and {} overflows", left, right));
+}
+
+fn product(left: usize, right: usize) -> ::Result<usize> {
+    if let Some(product) = left.checked_mul(right) {
+        return Ok(product);
+    }
+    raise!(format!("the product of {} and {} overflows", left, right));
+}
+
 pub mod banded;
+pub mod block;
 pub mod compressed;
 pub mod conventional;
 pub mod diagonal;
+mod elementwise;
+pub mod ellpack;
+mod kronecker;
+pub mod multidiagonal;
+mod multiply;
+mod operator;
 pub mod packed;
+mod reduction;
+pub mod rfp;
+pub mod skyline;
 
 pub use self::banded::Banded;
+pub use self::block::Block;
 pub use self::compressed::Compressed;
 pub use self::conventional::Conventional;
 pub use self::diagonal::Diagonal;
+pub use self::elementwise::Pattern;
+pub use self::ellpack::Ellpack;
+pub use self::multidiagonal::Multidiagonal;
 pub use self::packed::Packed;
+pub use self::reduction::Axis;
+pub use self::rfp::Rfp;
+pub use self::skyline::Skyline;
@@ -0,0 +1,290 @@
+use format::compressed::Variant;
+use format::{Banded, Compressed, Conventional, Multidiagonal};
+use Element;
+
+impl<'l, T: Element> From<&'l Banded<T>> for Multidiagonal<T> {
+    fn from(matrix: &'l Banded<T>) -> Self {
+        let &Banded {
+            rows,
+            columns,
+            subdiagonals,
+            ..
+        } = validate!(matrix);
+        let offsets = (0..matrix.diagonals())
+            .map(|k| k as isize - subdiagonals as isize)
+            .collect::<Vec<_>>();
+        let diagonals = offsets.len();
+        let mut result = Multidiagonal::new((rows, columns), offsets);
+        for (i, j, &value) in matrix.iter() {
+            let l = subdiagonals + j - i;
+            result.values[j * diagonals + l] = value;
+        }
+        result
+    }
+}
+
+impl<T: Element> From<Banded<T>> for Multidiagonal<T> {
+    #[inline]
+    fn from(matrix: Banded<T>) -> Self {
+        (&matrix).into()
+    }
+}
+
+impl<'l, T: Element> From<&'l Multidiagonal<T>> for Banded<T> {
+    fn from(matrix: &'l Multidiagonal<T>) -> Self {
+        let &Multidiagonal {
+            rows,
+            columns,
+            ref offsets,
+            ..
+        } = validate!(matrix);
+        let superdiagonals = offsets.last().map_or(0, |&offset| max!(offset, 0)) as usize;
+        let subdiagonals = offsets.first().map_or(0, |&offset| max!(-offset, 0)) as usize;
+        let mut result = Banded::new((rows, columns), superdiagonals, subdiagonals);
+        let diagonals = result.diagonals();
+        for (i, j, &value) in matrix.iter() {
+            let k = superdiagonals + i - j;
+            result.values[j * diagonals + k] = value;
+        }
+        result
+    }
+}
+
+impl<T: Element> From<Multidiagonal<T>> for Banded<T> {
+    #[inline]
+    fn from(matrix: Multidiagonal<T>) -> Self {
+        (&matrix).into()
+    }
+}
+
+impl<'l, T: Element> From<&'l Compressed<T>> for Multidiagonal<T> {
+    /// Convert from a compressed matrix.
+    ///
+    /// The diagonals containing at least one stored element are stored.
+    fn from(matrix: &'l Compressed<T>) -> Self {
+        from_triplets(
+            matrix.rows,
+            matrix.columns,
+            matrix.iter().map(|(i, j, &value)| (i, j, value)),
+        )
+    }
+}
+
+impl<T: Element> From<Compressed<T>> for Multidiagonal<T> {
+    #[inline]
+    fn from(matrix: Compressed<T>) -> Self {
+        (&matrix).into()
+    }
+}
+
+impl<'l, T: Element> From<&'l Multidiagonal<T>> for Compressed<T> {
+    /// Convert into a compressed matrix in the compressed-column variant.
+    ///
+    /// The zero elements are skipped.
+    fn from(matrix: &'l Multidiagonal<T>) -> Self {
+        let &Multidiagonal {
+            rows,
+            columns,
+            ref offsets,
+            ref values,
+        } = validate!(matrix);
+        let diagonals = offsets.len();
+        let mut result = Compressed::new((rows, columns), Variant::Column);
+        for j in 0..columns {
+            for (l, &offset) in offsets.iter().enumerate().rev() {
+                let i = row!(j, offset);
+                let value = values[j * diagonals + l];
+                if 0 <= i && i < rows as isize && !value.is_zero() {
+                    result.values.push(value);
+                    result.indices.push(i as usize);
+                }
+            }
+            result.offsets[j + 1] = result.values.len();
+        }
+        result.nonzeros = result.values.len();
+        result
+    }
+}
+
+impl<T: Element> From<Multidiagonal<T>> for Compressed<T> {
+    #[inline]
+    fn from(matrix: Multidiagonal<T>) -> Self {
+        (&matrix).into()
+    }
+}
+
+impl<'l, T: Element> From<&'l Conventional<T>> for Multidiagonal<T> {
+    /// Convert from a conventional matrix.
+    ///
+    /// The diagonals containing at least one nonzero element are stored.
+    fn from(matrix: &'l Conventional<T>) -> Self {
+        let rows = matrix.rows;
+        from_triplets(
+            rows,
+            matrix.columns,
+            matrix
+                .values
+                .iter()
+                .enumerate()
+                .filter(|&(_, value)| !value.is_zero())
+                .map(|(k, &value)| (k % rows, k / rows, value)),
+        )
+    }
+}
+
+impl<T: Element> From<Conventional<T>> for Multidiagonal<T> {
+    #[inline]
+    fn from(matrix: Conventional<T>) -> Self {
+        (&matrix).into()
+    }
+}
+
+impl<'l, T: Element> From<&'l Multidiagonal<T>> for Conventional<T> {
+    fn from(matrix: &'l Multidiagonal<T>) -> Self {
+        let rows = matrix.rows;
+        let mut result = Conventional::new((rows, matrix.columns));
+        for (i, j, &value) in validate!(matrix).iter() {
+            result.values[j * rows + i] = value;
+        }
+        result
+    }
+}
+
+impl<T: Element> From<Multidiagonal<T>> for Conventional<T> {
+    #[inline]
+    fn from(matrix: Multidiagonal<T>) -> Self {
+        (&matrix).into()
+    }
+}
+
+fn from_triplets<T, I>(rows: usize, columns: usize, triplets: I) -> Multidiagonal<T>
+where
+    T: Element,
+    I: Iterator<Item = (usize, usize, T)>,
+{
+    let triplets = triplets.collect::<Vec<_>>();
+    let offsets = triplets
+        .iter()
+        .map(|&(i, j, _)| j as isize - i as isize)
+        .collect();
+    let mut result = Multidiagonal::new((rows, columns), offsets);
+    let diagonals = result.diagonals();
+    for (i, j, value) in triplets {
+        let l = result
+            .offsets
+            .binary_search(&(j as isize - i as isize))
+            .unwrap();
+        result.values[j * diagonals + l] = value;
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use prelude::*;
+
+    #[test]
+    fn from_banded() {
+        let matrix = Conventional::from_vec(
+            (4, 5),
+            matrix![
+                1.0, 0.0, 7.0, 0.0, 0.0;
+                0.0, 2.0, 0.0, 8.0, 0.0;
+                5.0, 0.0, 3.0, 0.0, 9.0;
+                0.0, 6.0, 0.0, 4.0, 0.0;
+            ],
+        );
+        let banded = Banded::from(&new!(
+            4,
+            5,
+            vec![-2, 0, 2],
+            matrix![
+                5.0, 6.0, 0.0, 0.0, 0.0;
+                1.0, 2.0, 3.0, 4.0, 0.0;
+                0.0, 0.0, 7.0, 8.0, 9.0;
+            ]
+        ));
+        assert_eq!((banded.superdiagonals, banded.subdiagonals), (2, 2));
+        assert_eq!(Conventional::from(&banded), matrix);
+        let multidiagonal = Multidiagonal::from(&banded);
+        assert_eq!(multidiagonal.offsets, vec![-2, -1, 0, 1, 2]);
+        assert_eq!(Conventional::from(&multidiagonal), matrix);
+    }
+
+    #[test]
+    fn from_compressed() {
+        let multidiagonal = new!(
+            4,
+            5,
+            vec![-2, 0, 2],
+            matrix![
+                5.0, 6.0, 0.0, 0.0, 0.0;
+                1.0, 2.0, 3.0, 4.0, 0.0;
+                0.0, 0.0, 7.0, 8.0, 9.0;
+            ]
+        );
+        let matrix = Compressed::from(Conventional::from_vec(
+            (4, 5),
+            matrix![
+                1.0, 0.0, 7.0, 0.0, 0.0;
+                0.0, 2.0, 0.0, 8.0, 0.0;
+                5.0, 0.0, 3.0, 0.0, 9.0;
+                0.0, 6.0, 0.0, 4.0, 0.0;
+            ],
+        ));
+        assert_eq!(Multidiagonal::from(&matrix), multidiagonal);
+        assert_eq!(Compressed::from(multidiagonal), matrix);
+    }
+
+    #[test]
+    fn from_conventional() {
+        let matrix = Conventional::from_vec(
+            (4, 5),
+            matrix![
+                1.0, 0.0, 7.0, 0.0, 0.0;
+                0.0, 2.0, 0.0, 8.0, 0.0;
+                5.0, 0.0, 3.0, 0.0, 9.0;
+                0.0, 6.0, 0.0, 4.0, 0.0;
+            ],
+        );
+        assert_eq!(
+            Multidiagonal::from(matrix),
+            new!(
+                4,
+                5,
+                vec![-2, 0, 2],
+                matrix![
+                    5.0, 6.0, 0.0, 0.0, 0.0;
+                    1.0, 2.0, 3.0, 4.0, 0.0;
+                    0.0, 0.0, 7.0, 8.0, 9.0;
+                ]
+            )
+        );
+    }
+
+    #[test]
+    fn into_conventional() {
+        let matrix = new!(
+            4,
+            5,
+            vec![-2, 0, 2],
+            matrix![
+                5.0, 6.0, 0.0, 0.0, 0.0;
+                1.0, 2.0, 3.0, 4.0, 0.0;
+                0.0, 0.0, 7.0, 8.0, 9.0;
+            ]
+        );
+        assert_eq!(
+            Conventional::from(matrix),
+            Conventional::from_vec(
+                (4, 5),
+                matrix![
+                    1.0, 0.0, 7.0, 0.0, 0.0;
+                    0.0, 2.0, 0.0, 8.0, 0.0;
+                    5.0, 0.0, 3.0, 0.0, 9.0;
+                    0.0, 6.0, 0.0, 4.0, 0.0;
+                ],
+            )
+        );
+    }
+}
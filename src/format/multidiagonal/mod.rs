@@ -0,0 +1,208 @@
+//! The multidiagonal format.
+//!
+//! The format is suitable for matrices whose nonzero elements lie on a few
+//! diagonals that are not necessarily adjacent, such as those arising from
+//! stencils. Only the diagonals listed in `offsets` are stored; see the
+//! [diagonal format][1] for further details.
+//!
+//! [1]: http://netlib.org/linalg/html_templates/node94.html
+
+use std::iter;
+
+use format::product;
+use {Element, Matrix, Position, Result, Size};
+
+/// A multidiagonal matrix.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Multidiagonal<T: Element> {
+    /// The number of rows.
+    pub rows: usize,
+    /// The number of columns.
+    pub columns: usize,
+    /// The offsets of the stored diagonals in the increasing order such that
+    /// the diagonal with offset `d` consists of the elements `(i, i + d)`.
+    /// Positive offsets correspond to superdiagonals whereas negative ones to
+    /// subdiagonals.
+    pub offsets: Vec<isize>,
+    /// The values of the stored diagonals stored as a `offsets.len() ×
+    /// columns` matrix in the column-major order such that the element `(j -
+    /// offsets[l], j)` is stored in the `l`th row and `j`th column. The
+    /// elements that fall outside of the matrix are zero.
+    pub values: Vec<T>,
+}
+
+macro_rules! new(
+    ($rows:expr, $columns:expr, $offsets:expr, $values:expr) => (
+        Multidiagonal {
+            rows: $rows,
+            columns: $columns,
+            offsets: $offsets,
+            values: $values,
+        }
+    );
+);
+
+macro_rules! row(
+    ($column:expr, $offset:expr) => ({
+        let (column, offset) = ($column as isize, $offset);
+        column - offset
+    });
+);
+
+mod convert;
+mod operation;
+
+/// A sparse iterator.
+pub struct Iterator<'l, T: 'l + Element> {
+    matrix: &'l Multidiagonal<T>,
+    taken: usize,
+}
+
+#[cfg(debug_assertions)]
+impl<T: Element> ::format::Validate for Multidiagonal<T> {
+    fn validate(&self) {
+        assert_eq!(self.values.len(), self.diagonals() * self.columns);
+        assert!(self.offsets.windows(2).all(|pair| pair[0] < pair[1]));
+    }
+}
+
+size!(Multidiagonal);
+
+impl<T: Element> Multidiagonal<T> {
+    /// Create a zero matrix with the given diagonals.
+    pub fn new<S: Size>(size: S, mut offsets: Vec<isize>) -> Self {
+        let (rows, columns) = size.dimensions();
+        offsets.sort();
+        offsets.dedup();
+        let values = vec![T::zero(); offsets.len() * columns];
+        new!(rows, columns, offsets, values)
+    }
+
+    /// Create a matrix from its parts checking that they are consistent.
+    ///
+    /// The offsets should be strictly increasing, and the values should form
+    /// a `offsets.len() × columns` matrix.
+    pub fn from_parts<S: Size>(size: S, offsets: Vec<isize>, values: Vec<T>) -> Result<Self> {
+        let (rows, columns) = size.dimensions();
+        if offsets.windows(2).any(|pair| pair[0] >= pair[1]) {
+            raise!("the offsets should be strictly increasing");
+        }
+        let expected = product(offsets.len(), columns)?;
+        if values.len() != expected {
+            raise!(format!(
+                "{} diagonals and {} columns require {} values but {} have been given",
+                offsets.len(),
+                columns,
+                expected,
+                values.len()
+            ));
+        }
+        Ok(new!(rows, columns, offsets, values))
+    }
+
+    /// Return the number of stored diagonals.
+    #[inline]
+    pub fn diagonals(&self) -> usize {
+        self.offsets.len()
+    }
+
+    /// Read an element.
+    pub fn get<P: Position>(&self, position: P) -> T {
+        let (i, j) = position.coordinates();
+        debug_assert!(i < self.rows && j < self.columns);
+        match self.offsets.binary_search(&(j as isize - i as isize)) {
+            Ok(l) => self.values[j * self.diagonals() + l],
+            _ => T::zero(),
+        }
+    }
+
+    /// Return a sparse iterator.
+    ///
+    /// The iterator skips the stored elements that fall outside of the matrix.
+    #[inline]
+    pub fn iter<'l>(&'l self) -> Iterator<'l, T> {
+        Iterator {
+            matrix: self,
+            taken: 0,
+        }
+    }
+}
+
+impl<T: Element> Matrix for Multidiagonal<T> {
+    type Element = T;
+
+    fn nonzeros(&self) -> usize {
+        self.values
+            .iter()
+            .fold(0, |sum, &value| if value.is_zero() { sum } else { sum + 1 })
+    }
+
+    #[inline]
+    fn zero<S: Size>(size: S) -> Self {
+        Multidiagonal::new(size, vec![])
+    }
+}
+
+impl<'l, T: Element> iter::Iterator for Iterator<'l, T> {
+    type Item = (usize, usize, &'l T);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let &mut Iterator {
+            matrix,
+            ref mut taken,
+        } = self;
+        let diagonals = matrix.diagonals();
+        while *taken < matrix.values.len() {
+            let k = *taken;
+            *taken += 1;
+            let (l, j) = (k % diagonals, k / diagonals);
+            let i = row!(j, matrix.offsets[l]);
+            if 0 <= i && i < matrix.rows as isize {
+                return Some((i as usize, j, &matrix.values[k]));
+            }
+        }
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use prelude::*;
+
+    #[test]
+    fn from_parts() {
+        let matrix = Multidiagonal::from_parts(2, vec![-1, 1], vec![1.0, 0.0, 0.0, 2.0]).unwrap();
+        assert_eq!(matrix, new!(2, 2, vec![-1, 1], vec![1.0, 0.0, 0.0, 2.0]));
+        assert!(Multidiagonal::from_parts(2, vec![1, -1], vec![1.0; 4]).is_err());
+        assert!(Multidiagonal::from_parts(2, vec![-1, 1], vec![1.0; 3]).is_err());
+    }
+
+    #[test]
+    fn get() {
+        let matrix = new!(
+            3,
+            4,
+            vec![-2, 0, 3],
+            matrix![
+                5.0, 0.0, 0.0, 0.0;
+                1.0, 2.0, 3.0, 0.0;
+                0.0, 0.0, 0.0, 6.0;
+            ]
+        );
+        let conventional = Conventional::from_vec(
+            (3, 4),
+            matrix![
+                1.0, 0.0, 0.0, 6.0;
+                0.0, 2.0, 0.0, 0.0;
+                5.0, 0.0, 3.0, 0.0;
+            ],
+        );
+        for i in 0..3 {
+            for j in 0..4 {
+                assert_eq!(matrix.get((i, j)), conventional[(i, j)]);
+            }
+        }
+        assert_eq!(matrix.iter().count(), 5);
+        assert_eq!(matrix.nonzeros(), 5);
+    }
+}
@@ -0,0 +1,122 @@
+use format::Multidiagonal;
+use operation::{MultiplyInto, Transpose};
+use {Element, Number};
+
+impl<T> MultiplyInto<[T], [T]> for Multidiagonal<T>
+where
+    T: Element + Number,
+{
+    #[inline]
+    fn multiply_into(&self, right: &[T], result: &mut [T]) {
+        let (m, p) = (self.rows, self.columns);
+        let n = right.len() / p;
+        multiply_matrix_left(self, right, result, m, p, n)
+    }
+}
+
+impl<T: Element> Transpose for Multidiagonal<T> {
+    fn transpose(&self) -> Self {
+        let &Multidiagonal {
+            rows,
+            columns,
+            ref offsets,
+            ..
+        } = self;
+        let diagonals = self.diagonals();
+        let offsets = offsets.iter().rev().map(|&offset| -offset).collect();
+        let mut matrix = Multidiagonal::new((columns, rows), offsets);
+        for (i, j, &value) in self.iter() {
+            let l = matrix
+                .offsets
+                .binary_search(&(i as isize - j as isize))
+                .unwrap();
+            matrix.values[i * diagonals + l] = value;
+        }
+        matrix
+    }
+}
+
+fn multiply_matrix_left<T>(a: &Multidiagonal<T>, b: &[T], c: &mut [T], m: usize, p: usize, n: usize)
+where
+    T: Element + Number,
+{
+    debug_assert_eq!(a.rows * a.columns, m * p);
+    debug_assert_eq!(b.len(), p * n);
+    debug_assert_eq!(c.len(), m * n);
+    let (offsets, values) = (&a.offsets, &a.values);
+    let diagonals = a.diagonals();
+    for (l, &offset) in offsets.iter().enumerate() {
+        let start = max!(offset, 0) as usize;
+        let finish = min!(p as isize, m as isize + offset);
+        if finish <= start as isize {
+            continue;
+        }
+        for k in 0..n {
+            let (bo, co) = (k * p, k * m);
+            for j in start..(finish as usize) {
+                let i = (j as isize - offset) as usize;
+                c[co + i] = c[co + i] + values[j * diagonals + l] * b[bo + j];
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use prelude::*;
+
+    #[test]
+    fn multiply_into() {
+        let matrix: Multidiagonal<f64> = new!(
+            4,
+            5,
+            vec![-2, 0, 2],
+            matrix![
+                5.0, 6.0, 0.0, 0.0, 0.0;
+                1.0, 2.0, 3.0, 4.0, 0.0;
+                0.0, 0.0, 7.0, 8.0, 9.0;
+            ]
+        );
+        let right = Conventional::from_vec(
+            (5, 2),
+            matrix![
+                1.0, 5.0;
+                2.0, 4.0;
+                3.0, 3.0;
+                4.0, 2.0;
+                5.0, 1.0;
+            ],
+        );
+        let mut result = Conventional::from_vec((4, 2), vec![1.0; 8]);
+        matrix.multiply_into(&right, &mut result);
+        assert_eq!(
+            &result.values,
+            &matrix![
+                23.0, 27.0;
+                37.0, 25.0;
+                60.0, 44.0;
+                29.0, 33.0;
+            ]
+        );
+    }
+
+    #[test]
+    fn transpose() {
+        let matrix: Multidiagonal<f64> = new!(
+            4,
+            5,
+            vec![-2, 0, 2],
+            matrix![
+                5.0, 6.0, 0.0, 0.0, 0.0;
+                1.0, 2.0, 3.0, 4.0, 0.0;
+                0.0, 0.0, 7.0, 8.0, 9.0;
+            ]
+        );
+        let transpose = matrix.transpose();
+        assert_eq!(transpose.offsets, vec![-2, 0, 2]);
+        assert_eq!(
+            Conventional::from(&transpose),
+            Conventional::from(&matrix).transpose()
+        );
+    }
+}
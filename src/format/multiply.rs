@@ -0,0 +1,357 @@
+//! Products between different formats.
+//!
+//! The products are computed column by column. Each column of the result is a
+//! linear combination of the columns of the left operand with the coefficients
+//! taken from the corresponding column of the right operand, and only the
+//! elements that are nonzero according to the structure of each operand are
+//! visited. The products of a triangular packed matrix with a diagonal one or
+//! with another triangular packed matrix of the same variant can also be
+//! stored in the packed format, which keeps the result triangular.
+
+use std::borrow::Cow;
+
+use format::compressed::Variant;
+use format::{Banded, Compressed, Conventional, Diagonal, Packed};
+use operation::{Multiply, MultiplyInto};
+use {Element, Number, Size};
+
+/// A matrix whose columns can be traversed.
+pub trait Columns<T: Element>: Clone + Size {
+    /// Visit the structurally nonzero elements of a column in the increasing
+    /// order of their rows.
+    fn column<F>(&self, j: usize, callback: F)
+    where
+        F: FnMut(usize, T);
+
+    /// Return the matrix in a form whose columns can be visited in time
+    /// proportional to the number of their elements.
+    ///
+    /// Traversing all columns should be preceded by a call to this function.
+    #[inline]
+    fn columnar<'l>(&'l self) -> Cow<'l, Self> {
+        Cow::Borrowed(self)
+    }
+}
+
+macro_rules! dense(
+    ($left:ident, $right:ident) => (
+        impl<T> Multiply<$right<T>, Conventional<T>> for $left<T>
+        where
+            T: Element + Number,
+        {
+            fn multiply(&self, right: &$right<T>) -> Conventional<T> {
+                let mut result = Conventional::new((self.rows(), right.columns()));
+                self.multiply_into(right, &mut result.values);
+                result
+            }
+        }
+
+        multiply_into!($left, $right);
+    );
+);
+
+macro_rules! multiply_into(
+    ($left:ident, $right:ident) => (
+        impl<T> MultiplyInto<$right<T>, [T]> for $left<T>
+        where
+            T: Element + Number,
+        {
+            #[inline]
+            fn multiply_into(&self, right: &$right<T>, result: &mut [T]) {
+                multiply_dense(self, right, result)
+            }
+        }
+    );
+);
+
+macro_rules! sparse(
+    ($left:ident, $right:ident) => (
+        impl<T> Multiply<$right<T>, Compressed<T>> for $left<T>
+        where
+            T: Element + Number,
+        {
+            #[inline]
+            fn multiply(&self, right: &$right<T>) -> Compressed<T> {
+                multiply_sparse(self, right)
+            }
+        }
+
+        multiply_into!($left, $right);
+    );
+);
+
+multiply_into!(Banded, Banded);
+sparse!(Banded, Compressed);
+multiply_into!(Banded, Conventional);
+multiply_into!(Banded, Diagonal);
+dense!(Banded, Packed);
+
+sparse!(Compressed, Banded);
+sparse!(Compressed, Compressed);
+dense!(Compressed, Conventional);
+multiply_into!(Compressed, Diagonal);
+dense!(Compressed, Packed);
+
+multiply_into!(Conventional, Banded);
+dense!(Conventional, Diagonal);
+dense!(Conventional, Packed);
+
+multiply_into!(Diagonal, Banded);
+multiply_into!(Diagonal, Compressed);
+multiply_into!(Diagonal, Conventional);
+multiply_into!(Diagonal, Diagonal);
+dense!(Diagonal, Packed);
+
+dense!(Packed, Banded);
+dense!(Packed, Compressed);
+dense!(Packed, Conventional);
+dense!(Packed, Diagonal);
+dense!(Packed, Packed);
+
+impl<T> Multiply<Compressed<T>, Conventional<T>> for Conventional<T>
+where
+    T: Element + Number,
+{
+    fn multiply(&self, right: &Compressed<T>) -> Conventional<T> {
+        let mut result = Conventional::new((self.rows, right.columns));
+        self.multiply_into(right, &mut result.values);
+        result
+    }
+}
+
+pub fn multiply_dense<L, R, T>(left: &L, right: &R, result: &mut [T])
+where
+    L: Columns<T>,
+    R: Columns<T>,
+    T: Element + Number,
+{
+    let (m, n) = (left.rows(), right.columns());
+    debug_assert_eq!(left.columns(), right.rows());
+    debug_assert_eq!(result.len(), m * n);
+    let (left, right) = (left.columnar(), right.columnar());
+    for (j, result) in result.chunks_mut(max!(m, 1)).enumerate().take(n) {
+        right.column(j, |l, factor| {
+            if Element::is_zero(&factor) {
+                return;
+            }
+            left.column(l, |i, value| result[i] = result[i] + value * factor);
+        });
+    }
+}
+
+pub fn multiply_sparse<L, R, T>(left: &L, right: &R) -> Compressed<T>
+where
+    L: Columns<T>,
+    R: Columns<T>,
+    T: Element + Number,
+{
+    let (m, n) = (left.rows(), right.columns());
+    debug_assert_eq!(left.columns(), right.rows());
+    let (left, right) = (left.columnar(), right.columnar());
+    let mut result = Compressed::new((m, n), Variant::Column);
+    let mut buffer: Vec<T> = vec![Element::zero(); m];
+    let mut marked = vec![false; m];
+    let mut rows = vec![];
+    for j in 0..n {
+        right.column(j, |l, factor| {
+            left.column(l, |i, value| {
+                if !marked[i] {
+                    marked[i] = true;
+                    rows.push(i);
+                }
+                buffer[i] = buffer[i] + value * factor;
+            });
+        });
+        rows.sort();
+        for &i in &rows {
+            if !Element::is_zero(&buffer[i]) {
+                result.values.push(buffer[i]);
+                result.indices.push(i);
+            }
+            buffer[i] = Element::zero();
+            marked[i] = false;
+        }
+        rows.clear();
+        result.offsets[j + 1] = result.values.len();
+    }
+    result.nonzeros = result.values.len();
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use format::compressed;
+    use format::packed::{Kind, Variant};
+    use prelude::*;
+
+    fn multiply(left: &Conventional<f64>, right: &Conventional<f64>) -> Conventional<f64> {
+        let (m, p, n) = (left.rows, left.columns, right.columns);
+        let mut result = Conventional::new((m, n));
+        for i in 0..m {
+            for j in 0..n {
+                for l in 0..p {
+                    result[(i, j)] += left[(i, l)] * right[(l, j)];
+                }
+            }
+        }
+        result
+    }
+
+    macro_rules! check(
+        ($left:expr, $right:expr) => (check!($left, $right => _));
+        ($left:expr, $right:expr => $output:ty) => ({
+            let (left, right) = (&$left, &$right);
+            let expected = multiply(&left.clone().into(), &right.clone().into());
+            let product: $output = left.multiply(right);
+            assert_eq!(Conventional::from(product), expected);
+            let mut result = vec![1.0; 9];
+            left.multiply_into(right, &mut result);
+            let expected = expected.iter().map(|value| value + 1.0).collect::<Vec<_>>();
+            assert_eq!(result, expected);
+        });
+    );
+
+    #[test]
+    fn multiply_pairs() {
+        let conventional = Conventional::from_vec(
+            3,
+            matrix![
+                1.0, 2.0, 0.0;
+                3.0, 4.0, 5.0;
+                0.0, 6.0, 7.0;
+            ],
+        );
+        let banded = Banded::from(&conventional);
+        let mut compressed = Compressed::from(&conventional);
+        compressed.set((2, 0), 8.0);
+        let mut row = Compressed::new(3, compressed::Variant::Row);
+        for (i, j, &value) in compressed.iter() {
+            row.set((i, j), value);
+        }
+        let diagonal = Diagonal::from_vec(3, vec![1.0, -2.0, 3.0]);
+        let packed = Packed::from_conventional(
+            &Conventional::from_vec(
+                3,
+                matrix![
+                    1.0, 2.0, 3.0;
+                    2.0, 4.0, 5.0;
+                    3.0, 5.0, 6.0;
+                ],
+            ),
+            Variant::Upper,
+            Kind::Symmetric,
+            Some(0.0),
+        )
+        .unwrap();
+
+        check!(banded, banded);
+        check!(compressed, banded);
+        check!(conventional, banded);
+        check!(diagonal, banded);
+        check!(packed, banded);
+        check!(row, banded);
+
+        check!(banded, compressed);
+        check!(compressed, compressed);
+        check!(conventional, compressed);
+        check!(diagonal, compressed);
+        check!(packed, compressed);
+        check!(row, compressed);
+
+        check!(banded, conventional);
+        check!(compressed, conventional);
+        check!(conventional, conventional);
+        check!(diagonal, conventional);
+        check!(packed, conventional);
+        check!(row, conventional);
+
+        check!(banded, diagonal);
+        check!(compressed, diagonal);
+        check!(conventional, diagonal);
+        check!(diagonal, diagonal);
+        check!(packed, diagonal => Conventional<_>);
+        check!(row, diagonal);
+
+        check!(banded, packed);
+        check!(compressed, packed);
+        check!(conventional, packed);
+        check!(diagonal, packed => Conventional<_>);
+        check!(packed, packed => Conventional<_>);
+        check!(row, packed);
+
+        check!(banded, row);
+        check!(compressed, row);
+        check!(conventional, row);
+        check!(diagonal, row);
+        check!(packed, row);
+        check!(row, row);
+    }
+
+    #[test]
+    fn multiply_triangular() {
+        let diagonal = Diagonal::from_vec(3, vec![1.0, -2.0, 3.0]);
+        for &variant in &[Variant::Lower, Variant::Upper] {
+            let triangular = Packed::from_parts(
+                3,
+                variant,
+                Kind::Triangular,
+                vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0],
+            )
+            .unwrap();
+            let unit = Packed::from_parts(
+                3,
+                variant,
+                Kind::UnitTriangular,
+                vec![0.0, -1.0, 0.0, 2.0, 7.0, 0.0],
+            )
+            .unwrap();
+            check!(diagonal, triangular => Packed<_>);
+            check!(unit, diagonal => Packed<_>);
+            check!(triangular, unit => Packed<_>);
+            check!(unit, unit => Packed<_>);
+            let product: Packed<_> = triangular.multiply(&unit);
+            assert_eq!((product.variant, product.kind), (variant, Kind::Triangular));
+        }
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "the product of Lower and Upper triangular matrices is not triangular"
+    )]
+    fn multiply_triangular_variants() {
+        let lower = Packed::<f64>::new(2, Variant::Lower);
+        let upper = Packed::<f64>::new(2, Variant::Upper);
+        let _: Packed<_> = lower.multiply(&upper);
+    }
+
+    #[test]
+    fn multiply_sparse() {
+        let left = Compressed::from(Conventional::from_vec(
+            (2, 3),
+            matrix![
+                1.0, 0.0, 2.0;
+                0.0, 3.0, 0.0;
+            ],
+        ));
+        let right = Banded::from(Conventional::from_vec(
+            (3, 2),
+            matrix![
+                2.0, 0.0;
+                0.0, 1.0;
+               -1.0, 0.0;
+            ],
+        ));
+        let result = left.multiply(&right);
+        assert_eq!(result.nonzeros, 1);
+        assert_eq!(
+            Conventional::from(result),
+            Conventional::from_vec(
+                2,
+                matrix![
+                    0.0, 0.0;
+                    0.0, 3.0;
+                ],
+            )
+        );
+    }
+}
@@ -0,0 +1,808 @@
+//! Arithmetic operators.
+//!
+//! The sum and difference of two matrices are stored in the format that can
+//! accommodate the structures of both operands, and the product is stored in
+//! the format chosen by `Multiply`. The operators panic when the operands are
+//! incompatible, and the corresponding `checked_*` functions return an error
+//! instead.
+
+use std::ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+use format::compressed::Variant;
+use format::multiply::Columns;
+use format::packed::Kind;
+use format::{Banded, Compressed, Conventional, Diagonal, Packed};
+use operation::{CheckedAdd, CheckedMul, CheckedSub, Multiply};
+use {Element, Number, Result, Size};
+
+// A matrix that can absorb another matrix element by element.
+trait Absorb<R: ?Sized, T> {
+    fn absorb<F>(&mut self, right: &R, combine: F) -> Result<()>
+    where
+        F: Fn(T, T) -> T;
+}
+
+// A matrix whose elements can be transformed one by one. The transformation
+// should map zero to zero.
+trait Transform<T> {
+    fn transform<F>(&mut self, map: F)
+    where
+        F: Fn(T) -> T;
+
+    // Multiply the elements by a scalar checking that the kind of the matrix
+    // is preserved.
+    #[inline]
+    fn scale(&mut self, factor: T) -> Result<()>
+    where
+        T: Element + Number,
+    {
+        self.transform(|value| value * factor);
+        Ok(())
+    }
+}
+
+macro_rules! delegate(
+    ($left:ident, $right:ident => $output:ident,
+     $operator:ident, $method:ident, $checked:ident) => (
+        impl<'l, 'r, T> $operator<&'r $right<T>> for &'l $left<T>
+        where
+            T: 'static + Element + Number,
+        {
+            type Output = $output<T>;
+
+            #[inline]
+            fn $method(self, right: &'r $right<T>) -> Self::Output {
+                expect(self.$checked(right))
+            }
+        }
+
+        impl<'l, T> $operator<$right<T>> for &'l $left<T>
+        where
+            T: 'static + Element + Number,
+        {
+            type Output = $output<T>;
+
+            #[inline]
+            fn $method(self, right: $right<T>) -> Self::Output {
+                expect(self.$checked(&right))
+            }
+        }
+    );
+    ($left:ident, $right:ident => $output:ident,
+     $operator:ident, $method:ident, $checked:ident, owned) => (
+        delegate!($left, $right => $output, $operator, $method, $checked);
+
+        impl<'r, T> $operator<&'r $right<T>> for $left<T>
+        where
+            T: 'static + Element + Number,
+        {
+            type Output = $output<T>;
+
+            #[inline]
+            fn $method(self, right: &'r $right<T>) -> Self::Output {
+                expect(self.$checked(right))
+            }
+        }
+
+        impl<T> $operator<$right<T>> for $left<T>
+        where
+            T: 'static + Element + Number,
+        {
+            type Output = $output<T>;
+
+            #[inline]
+            fn $method(self, right: $right<T>) -> Self::Output {
+                expect(self.$checked(&right))
+            }
+        }
+    );
+);
+
+macro_rules! assign(
+    ($left:ident, $right:ident, $assign:ident, $method:ident, $body:expr) => (
+        impl<'r, T> $assign<&'r $right<T>> for $left<T>
+        where
+            T: 'static + Element + Number,
+        {
+            #[inline]
+            fn $method(&mut self, right: &'r $right<T>) {
+                $body(self, right)
+            }
+        }
+
+        impl<T> $assign<$right<T>> for $left<T>
+        where
+            T: 'static + Element + Number,
+        {
+            #[inline]
+            fn $method(&mut self, right: $right<T>) {
+                $assign::$method(self, &right)
+            }
+        }
+    );
+);
+
+macro_rules! same(
+    ($left:ident, $right:ident) => (
+        same!($left, $right, CheckedAdd, checked_add, Add, add, AddAssign, add_assign,
+              |a, b| a + b);
+        same!($left, $right, CheckedSub, checked_sub, Sub, sub, SubAssign, sub_assign,
+              |a, b| a - b);
+    );
+    ($left:ident, $right:ident, $checked:ident, $checked_method:ident,
+     $operator:ident, $method:ident, $assign:ident, $assign_method:ident,
+     $combine:expr) => (
+        impl<T> $checked<$right<T>, $left<T>> for $left<T>
+        where
+            T: 'static + Element + Number,
+        {
+            fn $checked_method(&self, right: &$right<T>) -> Result<$left<T>> {
+                check(self, right)?;
+                let mut result = self.clone();
+                result.absorb(right, $combine)?;
+                Ok(result)
+            }
+        }
+
+        delegate!($left, $right => $left, $operator, $method, $checked_method);
+
+        impl<'r, T> $operator<&'r $right<T>> for $left<T>
+        where
+            T: 'static + Element + Number,
+        {
+            type Output = $left<T>;
+
+            #[inline]
+            fn $method(mut self, right: &'r $right<T>) -> Self::Output {
+                $assign::$assign_method(&mut self, right);
+                self
+            }
+        }
+
+        impl<T> $operator<$right<T>> for $left<T>
+        where
+            T: 'static + Element + Number,
+        {
+            type Output = $left<T>;
+
+            #[inline]
+            fn $method(mut self, right: $right<T>) -> Self::Output {
+                $assign::$assign_method(&mut self, &right);
+                self
+            }
+        }
+
+        assign!($left, $right, $assign, $assign_method, |left: &mut $left<T>, right| {
+            expect(check(left, right));
+            expect(left.absorb(right, $combine));
+        });
+    );
+);
+
+macro_rules! convert(
+    ($left:ident, $right:ident => $output:ident) => (
+        convert!($left, $right => $output, CheckedAdd, checked_add, Add, add, |a, b| a + b);
+        convert!($left, $right => $output, CheckedSub, checked_sub, Sub, sub, |a, b| a - b);
+    );
+    ($left:ident, $right:ident => $output:ident, $checked:ident, $checked_method:ident,
+     $operator:ident, $method:ident, $combine:expr) => (
+        impl<T> $checked<$right<T>, $output<T>> for $left<T>
+        where
+            T: 'static + Element + Number,
+        {
+            fn $checked_method(&self, right: &$right<T>) -> Result<$output<T>> {
+                check(self, right)?;
+                let mut result = $output::from(self);
+                result.absorb(right, $combine)?;
+                Ok(result)
+            }
+        }
+
+        delegate!($left, $right => $output, $operator, $method, $checked_method, owned);
+    );
+);
+
+macro_rules! product(
+    ($left:ident, $right:ident => $output:ident) => (
+        impl<T> CheckedMul<$right<T>, $output<T>> for $left<T>
+        where
+            T: 'static + Element + Number,
+        {
+            fn checked_mul(&self, right: &$right<T>) -> Result<$output<T>> {
+                if self.columns() != right.rows() {
+                    raise!(format!(
+                        "cannot multiply a {} × {} matrix by a {} × {} matrix",
+                        self.rows(),
+                        self.columns(),
+                        right.rows(),
+                        right.columns()
+                    ));
+                }
+                Ok(self.multiply(right))
+            }
+        }
+
+        delegate!($left, $right => $output, Mul, mul, checked_mul, owned);
+    );
+    ($left:ident, $right:ident => $output:ident, assign) => (
+        product!($left, $right => $output);
+
+        assign!($left, $right, MulAssign, mul_assign, |left: &mut $left<T>, right| {
+            *left = expect(left.checked_mul(right));
+        });
+    );
+);
+
+macro_rules! scalar(
+    ($kind:ident) => (
+        impl<T> CheckedMul<T, $kind<T>> for $kind<T>
+        where
+            T: 'static + Element + Number,
+        {
+            fn checked_mul(&self, right: &T) -> Result<$kind<T>> {
+                let mut result = self.clone();
+                result.scale(*right)?;
+                Ok(result)
+            }
+        }
+
+        impl<T> Mul<T> for $kind<T>
+        where
+            T: 'static + Element + Number,
+        {
+            type Output = $kind<T>;
+
+            #[inline]
+            fn mul(mut self, right: T) -> Self::Output {
+                self *= right;
+                self
+            }
+        }
+
+        impl<'l, T> Mul<T> for &'l $kind<T>
+        where
+            T: 'static + Element + Number,
+        {
+            type Output = $kind<T>;
+
+            #[inline]
+            fn mul(self, right: T) -> Self::Output {
+                self.clone() * right
+            }
+        }
+
+        impl<T> MulAssign<T> for $kind<T>
+        where
+            T: 'static + Element + Number,
+        {
+            #[inline]
+            fn mul_assign(&mut self, right: T) {
+                expect(self.scale(right));
+            }
+        }
+
+        impl<T> Neg for $kind<T>
+        where
+            T: 'static + Element + Number + Neg<Output = T>,
+        {
+            type Output = $kind<T>;
+
+            #[inline]
+            fn neg(mut self) -> Self::Output {
+                self.transform(|value| -value);
+                self
+            }
+        }
+
+        impl<'l, T> Neg for &'l $kind<T>
+        where
+            T: 'static + Element + Number + Neg<Output = T>,
+        {
+            type Output = $kind<T>;
+
+            #[inline]
+            fn neg(self) -> Self::Output {
+                -self.clone()
+            }
+        }
+    );
+);
+
+macro_rules! transform(
+    ($kind:ident) => (
+        impl<T: Element> Transform<T> for $kind<T> {
+            #[inline]
+            fn transform<F>(&mut self, map: F)
+            where
+                F: Fn(T) -> T,
+            {
+                for value in &mut self.values {
+                    *value = map(*value);
+                }
+            }
+        }
+    );
+);
+
+same!(Banded, Banded);
+same!(Banded, Diagonal);
+convert!(Banded, Compressed => Compressed);
+convert!(Banded, Conventional => Conventional);
+convert!(Banded, Packed => Conventional);
+
+same!(Compressed, Banded);
+same!(Compressed, Compressed);
+same!(Compressed, Diagonal);
+convert!(Compressed, Conventional => Conventional);
+convert!(Compressed, Packed => Conventional);
+
+same!(Conventional, Banded);
+same!(Conventional, Compressed);
+same!(Conventional, Conventional);
+same!(Conventional, Diagonal);
+same!(Conventional, Packed);
+
+convert!(Diagonal, Banded => Banded);
+convert!(Diagonal, Compressed => Compressed);
+convert!(Diagonal, Conventional => Conventional);
+same!(Diagonal, Diagonal);
+delegate!(Diagonal, Packed => Packed, Add, add, checked_add, owned);
+delegate!(Diagonal, Packed => Packed, Sub, sub, checked_sub, owned);
+
+convert!(Packed, Banded => Conventional);
+convert!(Packed, Compressed => Conventional);
+convert!(Packed, Conventional => Conventional);
+same!(Packed, Diagonal);
+convert!(Packed, Packed => Conventional);
+
+product!(Banded, Banded => Banded, assign);
+product!(Banded, Compressed => Compressed);
+product!(Banded, Conventional => Conventional);
+product!(Banded, Diagonal => Banded, assign);
+product!(Banded, Packed => Conventional);
+
+product!(Compressed, Banded => Compressed, assign);
+product!(Compressed, Compressed => Compressed, assign);
+product!(Compressed, Conventional => Conventional);
+product!(Compressed, Diagonal => Compressed, assign);
+product!(Compressed, Packed => Conventional);
+
+product!(Conventional, Banded => Conventional, assign);
+product!(Conventional, Compressed => Conventional, assign);
+product!(Conventional, Conventional => Conventional, assign);
+product!(Conventional, Diagonal => Conventional, assign);
+product!(Conventional, Packed => Conventional, assign);
+
+product!(Diagonal, Banded => Banded);
+product!(Diagonal, Compressed => Compressed);
+product!(Diagonal, Conventional => Conventional);
+product!(Diagonal, Diagonal => Diagonal, assign);
+product!(Diagonal, Packed => Conventional);
+
+product!(Packed, Banded => Conventional);
+product!(Packed, Compressed => Conventional);
+product!(Packed, Conventional => Conventional);
+product!(Packed, Diagonal => Conventional);
+product!(Packed, Packed => Conventional);
+
+scalar!(Banded);
+scalar!(Compressed);
+scalar!(Conventional);
+scalar!(Diagonal);
+scalar!(Packed);
+
+transform!(Banded);
+transform!(Compressed);
+transform!(Conventional);
+transform!(Diagonal);
+
+impl<T> CheckedAdd<Packed<T>, Packed<T>> for Diagonal<T>
+where
+    T: 'static + Element + Number,
+{
+    fn checked_add(&self, right: &Packed<T>) -> Result<Packed<T>> {
+        check(self, right)?;
+        let mut result = right.clone();
+        result.absorb(self, |a, b| a + b)?;
+        Ok(result)
+    }
+}
+
+impl<T> CheckedSub<Packed<T>, Packed<T>> for Diagonal<T>
+where
+    T: 'static + Element + Number,
+{
+    fn checked_sub(&self, right: &Packed<T>) -> Result<Packed<T>> {
+        check(self, right)?;
+        let mut result = right.clone();
+        let zero: T = Element::zero();
+        result.transform(|value| zero - value);
+        result.absorb(self, |a, b| a + b)?;
+        Ok(result)
+    }
+}
+
+impl<T: Element> Transform<T> for Packed<T> {
+    fn transform<F>(&mut self, map: F)
+    where
+        F: Fn(T) -> T,
+    {
+        self.materialize();
+        for value in &mut self.values {
+            *value = map(*value);
+        }
+    }
+
+    // A Hermitian matrix multiplied by a scalar that is not real is not
+    // Hermitian.
+    fn scale(&mut self, factor: T) -> Result<()>
+    where
+        T: Element + Number,
+    {
+        if self.kind == Kind::Hermitian && factor.conj() != factor {
+            raise!("a Hermitian matrix can only be multiplied by a real scalar");
+        }
+        self.transform(|value| value * factor);
+        Ok(())
+    }
+}
+
+impl<T: Element> Absorb<Banded<T>, T> for Banded<T> {
+    fn absorb<F>(&mut self, right: &Banded<T>, combine: F) -> Result<()>
+    where
+        F: Fn(T, T) -> T,
+    {
+        let superdiagonals = max!(self.superdiagonals, right.superdiagonals);
+        let subdiagonals = max!(self.subdiagonals, right.subdiagonals);
+        self.resize(superdiagonals, subdiagonals);
+        for (i, j, &value) in right.iter() {
+            let current = self.get((i, j));
+            self.set((i, j), combine(current, value));
+        }
+        Ok(())
+    }
+}
+
+impl<T: Element> Absorb<Diagonal<T>, T> for Banded<T> {
+    fn absorb<F>(&mut self, right: &Diagonal<T>, combine: F) -> Result<()>
+    where
+        F: Fn(T, T) -> T,
+    {
+        for (i, &value) in right.values.iter().enumerate() {
+            let current = self.get((i, i));
+            self.set((i, i), combine(current, value));
+        }
+        Ok(())
+    }
+}
+
+impl<R, T> Absorb<R, T> for Compressed<T>
+where
+    R: Columns<T>,
+    T: Element,
+{
+    fn absorb<F>(&mut self, right: &R, combine: F) -> Result<()>
+    where
+        F: Fn(T, T) -> T,
+    {
+        let &mut Compressed {
+            rows,
+            columns,
+            variant,
+            ..
+        } = self;
+        let zero: T = Element::zero();
+        let mut left = vec![vec![]; columns];
+        for (i, j, &value) in self.iter() {
+            left[j].push((i, value));
+        }
+        let right = right.columnar();
+        let mut triplets = Vec::with_capacity(self.nonzeros);
+        let mut column = vec![];
+        for (j, left) in left.into_iter().enumerate() {
+            right.column(j, |i, value| {
+                if !Element::is_zero(&value) {
+                    column.push((i, value));
+                }
+            });
+            let (mut k, mut l) = (0, 0);
+            while k < left.len() || l < column.len() {
+                if l == column.len() || (k < left.len() && left[k].0 < column[l].0) {
+                    triplets.push((left[k].0, j, left[k].1));
+                    k += 1;
+                } else if k == left.len() || column[l].0 < left[k].0 {
+                    triplets.push((column[l].0, j, combine(zero, column[l].1)));
+                    l += 1;
+                } else {
+                    triplets.push((left[k].0, j, combine(left[k].1, column[l].1)));
+                    k += 1;
+                    l += 1;
+                }
+            }
+            column.clear();
+        }
+        if let Variant::Row = variant {
+            triplets.sort_by_key(|&(i, j, _)| (i, j));
+        }
+        *self = Compressed::assemble((rows, columns), variant, triplets);
+        Ok(())
+    }
+}
+
+impl<R, T> Absorb<R, T> for Conventional<T>
+where
+    R: Columns<T>,
+    T: Element,
+{
+    fn absorb<F>(&mut self, right: &R, combine: F) -> Result<()>
+    where
+        F: Fn(T, T) -> T,
+    {
+        let (rows, right) = (self.rows, right.columnar());
+        for (j, values) in self.values.chunks_mut(max!(rows, 1)).enumerate() {
+            right.column(j, |i, value| values[i] = combine(values[i], value));
+        }
+        Ok(())
+    }
+}
+
+impl<T: Element> Absorb<Diagonal<T>, T> for Diagonal<T> {
+    fn absorb<F>(&mut self, right: &Diagonal<T>, combine: F) -> Result<()>
+    where
+        F: Fn(T, T) -> T,
+    {
+        for (value, &other) in self.values.iter_mut().zip(&right.values) {
+            *value = combine(*value, other);
+        }
+        Ok(())
+    }
+}
+
+impl<T: Element> Absorb<Diagonal<T>, T> for Packed<T> {
+    fn absorb<F>(&mut self, right: &Diagonal<T>, combine: F) -> Result<()>
+    where
+        F: Fn(T, T) -> T,
+    {
+        if self.kind == Kind::Hermitian && right.values.iter().any(|value| value.conj() != *value) {
+            raise!("a Hermitian matrix can only be combined with a real diagonal");
+        }
+        self.materialize();
+        for (i, &value) in right.values.iter().enumerate() {
+            let current = self.get((i, i));
+            self.set((i, i), combine(current, value));
+        }
+        Ok(())
+    }
+}
+
+fn check<L: Size, R: Size>(left: &L, right: &R) -> Result<()> {
+    let ((m, n), (p, q)) = (left.dimensions(), right.dimensions());
+    if m != p || n != q {
+        raise!(format!(
+            "the dimensions of the operands do not match ({} × {} and {} × {})",
+            m, n, p, q
+        ));
+    }
+    Ok(())
+}
+
+fn expect<T>(result: Result<T>) -> T {
+    match result {
+        Ok(value) => value,
+        Err(error) => panic!("{}", error),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use c64;
+    use format::compressed::Variant;
+    use format::packed::{self, Kind};
+    use prelude::*;
+
+    #[test]
+    fn add() {
+        let conventional = Conventional::from_vec(
+            3,
+            matrix![
+                1.0, 2.0, 0.0;
+                3.0, 4.0, 5.0;
+                0.0, 6.0, 7.0;
+            ],
+        );
+        let banded = Banded::from(&conventional);
+        let diagonal = Diagonal::from_vec(3, vec![1.0, 2.0, 3.0]);
+        let sum: Banded<_> = &banded + &diagonal;
+        assert_eq!(
+            Conventional::from(sum),
+            Conventional::from_vec(
+                3,
+                matrix![
+                    2.0, 2.0,  0.0;
+                    3.0, 6.0,  5.0;
+                    0.0, 6.0, 10.0;
+                ],
+            )
+        );
+        let sum: Banded<_> = banded.clone() + Banded::from(diagonal.clone());
+        assert_eq!((sum.superdiagonals, sum.subdiagonals), (1, 1));
+        let sum: Conventional<_> = &conventional + &conventional;
+        assert_eq!(sum, conventional.clone() * 2.0);
+        let sum: Banded<_> = &diagonal + &banded;
+        assert_eq!(
+            Conventional::from(sum),
+            Conventional::from(&banded + &diagonal)
+        );
+    }
+
+    #[test]
+    fn add_assign() {
+        let mut conventional = Conventional::from_vec(
+            3,
+            matrix![
+                1.0, 2.0, 0.0;
+                3.0, 4.0, 5.0;
+                0.0, 6.0, 7.0;
+            ],
+        );
+        let right = Compressed::from(conventional.clone());
+        conventional += Diagonal::from_vec(3, vec![1.0, 1.0, 1.0]);
+        conventional -= &right;
+        assert_eq!(
+            conventional,
+            Conventional::from(Diagonal::from_vec(3, vec![1.0; 3]))
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "the dimensions of the operands do not match (3 × 3 and 2 × 2)")]
+    fn add_mismatch() {
+        let conventional = Conventional::from_vec(3, vec![1.0; 9]);
+        let _ = conventional + Diagonal::from_vec(2, vec![1.0, 2.0]);
+    }
+
+    #[test]
+    fn checked() {
+        let conventional = Conventional::from_vec(
+            3,
+            matrix![
+                1.0, 2.0, 0.0;
+                3.0, 4.0, 5.0;
+                0.0, 6.0, 7.0;
+            ],
+        );
+        let diagonal = Diagonal::from_vec((3, 2), vec![1.0, 2.0]);
+        assert!(conventional.checked_add(&diagonal).is_err());
+        assert!(conventional.checked_mul(&diagonal).is_ok());
+        assert!(diagonal.checked_mul(&conventional).is_err());
+        let sum: Conventional<_> = conventional.checked_sub(&conventional).unwrap();
+        assert_eq!(sum, Conventional::new(3));
+    }
+
+    #[test]
+    fn compressed() {
+        let mut left = Compressed::new(3, Variant::Row);
+        left.set((0, 2), 1.0);
+        left.set((2, 0), 2.0);
+        let right = Compressed::from(Conventional::from_vec(
+            3,
+            matrix![
+                1.0, 2.0, 0.0;
+                3.0, 4.0, 5.0;
+                0.0, 6.0, 7.0;
+            ],
+        ));
+        let sum: Compressed<_> = &left + &right;
+        assert_eq!(sum.variant, Variant::Row);
+        assert_eq!(sum.nonzeros, 9);
+        assert_eq!(
+            Conventional::from(sum),
+            Conventional::from_vec(
+                3,
+                matrix![
+                    1.0, 2.0, 1.0;
+                    3.0, 4.0, 5.0;
+                    2.0, 6.0, 7.0;
+                ],
+            )
+        );
+        let difference: Compressed<_> = &right - &right;
+        assert_eq!(Conventional::from(difference), Conventional::new(3));
+    }
+
+    #[test]
+    fn mul() {
+        let conventional = Conventional::from_vec(
+            3,
+            matrix![
+                1.0, 2.0, 0.0;
+                3.0, 4.0, 5.0;
+                0.0, 6.0, 7.0;
+            ],
+        );
+        let diagonal = Diagonal::from_vec(3, vec![1.0, 2.0, 3.0]);
+        let product: Conventional<_> = &conventional * &diagonal;
+        assert_eq!(product, conventional.multiply(&diagonal));
+        let mut product = conventional.clone();
+        product *= &conventional;
+        assert_eq!(product, conventional.multiply(&conventional));
+        let product: Banded<_> = &diagonal * Banded::from(&conventional);
+        assert_eq!(product.get((2, 1)), 18.0);
+    }
+
+    #[test]
+    fn neg() {
+        let diagonal = Diagonal::from_vec(2, vec![1.0, -2.0]);
+        assert_eq!(-&diagonal, Diagonal::from_vec(2, vec![-1.0, 2.0]));
+        assert_eq!(-diagonal.clone(), diagonal * -1.0);
+    }
+
+    #[test]
+    fn packed() {
+        let symmetric = Packed::from_parts(
+            2,
+            packed::Variant::Lower,
+            Kind::Symmetric,
+            vec![1.0, 2.0, 3.0],
+        )
+        .unwrap();
+        let other = Packed::from_parts(
+            2,
+            packed::Variant::Upper,
+            Kind::Symmetric,
+            vec![1.0, 1.0, 1.0],
+        )
+        .unwrap();
+        let sum: Conventional<_> = &symmetric + &other;
+        assert_eq!(&sum.values, &[2.0, 3.0, 3.0, 4.0]);
+        let unit = Packed::from_parts(
+            2,
+            packed::Variant::Lower,
+            Kind::UnitTriangular,
+            vec![0.0, 5.0, 0.0],
+        )
+        .unwrap();
+        let sum: Conventional<_> = &symmetric + &unit;
+        assert_eq!(
+            sum,
+            Conventional::from_vec(
+                2,
+                matrix![
+                    2.0, 2.0;
+                    7.0, 4.0;
+                ],
+            )
+        );
+        let scaled = &unit * 2.0;
+        assert_eq!(scaled.kind, Kind::Triangular);
+        assert_eq!(&scaled.values, &[2.0, 10.0, 2.0]);
+        let difference: Packed<_> = &Diagonal::from_vec(2, vec![1.0, 1.0]) - &unit;
+        assert_eq!(&difference.values, &[0.0, -5.0, 0.0]);
+        assert_eq!(difference.kind, Kind::Triangular);
+    }
+
+    #[test]
+    fn packed_hermitian() {
+        let hermitian = Packed::from_parts(
+            2,
+            packed::Variant::Lower,
+            Kind::Hermitian,
+            vec![c64::new(1.0, 0.0), c64::new(2.0, 3.0), c64::new(4.0, 0.0)],
+        )
+        .unwrap();
+        let scaled = &hermitian * c64::new(2.0, 0.0);
+        assert_eq!(scaled.kind, Kind::Hermitian);
+        assert_eq!(scaled.values[1], c64::new(4.0, 6.0));
+        assert!(hermitian.checked_mul(&c64::new(0.0, 1.0)).is_err());
+        let diagonal = Diagonal::from_vec(2, vec![c64::new(1.0, 1.0), c64::new(1.0, 0.0)]);
+        assert!(hermitian.checked_add(&diagonal).is_err());
+    }
+
+    #[test]
+    #[should_panic(expected = "a Hermitian matrix can only be multiplied by a real scalar")]
+    fn packed_hermitian_complex() {
+        let mut hermitian = Packed::new(2, packed::Variant::Lower);
+        hermitian.kind = Kind::Hermitian;
+        hermitian *= c64::new(0.0, 1.0);
+    }
+}
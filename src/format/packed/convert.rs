@@ -1,36 +1,105 @@
-use format::packed::Variant;
-use format::{Conventional, Packed};
-use Element;
+use std::iter;
+
+use format::packed::{Kind, Variant};
+use format::{Compressed, Conventional, Packed};
+use {Element, Number, Result};
+
+impl<T> Packed<T>
+where
+    T: Element + Number,
+{
+    /// Create a matrix of a specific kind from the lower triangle when
+    /// `variant = Lower` or upper triangle when `variant = Upper` of a
+    /// compressed matrix.
+    ///
+    /// If `tolerance` is given, the function fails unless the matrix is of the
+    /// given kind up to the tolerance.
+    pub fn from_compressed(
+        matrix: &Compressed<T>,
+        variant: Variant,
+        kind: Kind,
+        tolerance: Option<f64>,
+    ) -> Result<Self> {
+        let size = super::square((matrix.rows, matrix.columns))?;
+        if let Some(tolerance) = tolerance {
+            check(
+                size,
+                variant,
+                kind,
+                tolerance,
+                |i, j| matrix.get((i, j)),
+                matrix.iter().map(|(i, j, &value)| (i, j, value)),
+            )?;
+        }
+        let mut result = new!(size, variant, kind, vec![Element::zero(); storage!(size)]);
+        for (i, j, &value) in matrix.iter() {
+            if let Some(k) = result.position(i, j) {
+                result.values[k] = value;
+            }
+        }
+        Ok(result)
+    }
+
+    /// Create a matrix of a specific kind from the lower triangle when
+    /// `variant = Lower` or upper triangle when `variant = Upper` of a
+    /// conventional matrix.
+    ///
+    /// If `tolerance` is given, the function fails unless the matrix is of the
+    /// given kind up to the tolerance.
+    pub fn from_conventional(
+        matrix: &Conventional<T>,
+        variant: Variant,
+        kind: Kind,
+        tolerance: Option<f64>,
+    ) -> Result<Self> {
+        let size = super::square((matrix.rows, matrix.columns))?;
+        if let Some(tolerance) = tolerance {
+            check(
+                size,
+                variant,
+                kind,
+                tolerance,
+                |i, j| matrix[(i, j)],
+                matrix
+                    .values
+                    .iter()
+                    .enumerate()
+                    .map(|(k, &value)| (k % size, k / size, value)),
+            )?;
+        }
+        let mut result = new!(size, variant, kind, vec![Element::zero(); storage!(size)]);
+        for (i, j, value) in result.iter_mut() {
+            *value = matrix[(i, j)];
+        }
+        Ok(result)
+    }
+}
 
 impl<'l, T: Element> From<&'l Packed<T>> for Conventional<T> {
+    /// Convert into a conventional matrix.
+    ///
+    /// The triangle that is not stored is filled in according to the kind of
+    /// the matrix.
     fn from(matrix: &'l Packed<T>) -> Self {
-        let &Packed {
-            size,
-            variant,
-            ref values,
-        } = validate!(matrix);
-        let mut matrix = Conventional::new(size);
-        match variant {
-            Variant::Lower => {
-                let mut k = 0;
-                for j in 0..size {
-                    for i in j..size {
-                        matrix.values[j * size + i] = values[k];
-                        k += 1;
-                    }
-                }
+        let kind = validate!(matrix).kind;
+        let size = matrix.size;
+        let mut result = Conventional::new(size);
+        for (i, j, &value) in matrix.iter() {
+            if i == j {
+                result.values[j * size + i] = match kind {
+                    Kind::UnitTriangular => T::one(),
+                    _ => value,
+                };
+                continue;
             }
-            Variant::Upper => {
-                let mut k = 0;
-                for j in 0..size {
-                    for i in 0..(j + 1) {
-                        matrix.values[j * size + i] = values[k];
-                        k += 1;
-                    }
-                }
+            result.values[j * size + i] = value;
+            match kind {
+                Kind::Symmetric => result.values[i * size + j] = value,
+                Kind::Hermitian => result.values[i * size + j] = value.conj(),
+                _ => {}
             }
         }
-        matrix
+        result
     }
 }
 
@@ -41,11 +110,195 @@ impl<T: Element> From<Packed<T>> for Conventional<T> {
     }
 }
 
+fn check<T, F, I>(
+    size: usize,
+    variant: Variant,
+    kind: Kind,
+    tolerance: f64,
+    get: F,
+    elements: I,
+) -> Result<()>
+where
+    T: Element + Number,
+    F: Fn(usize, usize) -> T,
+    I: iter::Iterator<Item = (usize, usize, T)>,
+{
+    let name = match kind {
+        Kind::Triangular => "triangular",
+        Kind::UnitTriangular => "unit triangular",
+        Kind::Symmetric => "symmetric",
+        Kind::Hermitian => "Hermitian",
+    };
+    let lower = variant == Variant::Lower;
+    for (i, j, value) in elements {
+        let deviation = match kind {
+            Kind::Triangular | Kind::UnitTriangular => {
+                if i == j || (i > j) == lower {
+                    continue;
+                }
+                value.magnitude()
+            }
+            Kind::Symmetric => (value - get(j, i)).magnitude(),
+            Kind::Hermitian => (value - get(j, i).conj()).magnitude(),
+        };
+        if deviation > tolerance {
+            raise!(format!(
+                "the matrix is not {} (element ({}, {}) deviates by {})",
+                name, i, j, deviation
+            ));
+        }
+    }
+    if kind == Kind::UnitTriangular {
+        for i in 0..size {
+            let deviation = (get(i, i) - Element::one()).magnitude();
+            if deviation > tolerance {
+                raise!(format!(
+                    "the matrix is not {} (element ({}, {}) deviates by {})",
+                    name, i, i, deviation
+                ));
+            }
+        }
+    }
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
-    use format::packed::Variant;
+    use c64;
+    use format::packed::{Kind, Variant};
     use prelude::*;
 
+    #[test]
+    fn from_compressed() {
+        let mut matrix = Compressed::new(3, compressed::Variant::Column);
+        matrix.set((0, 0), 1.0);
+        matrix.set((2, 0), 2.0);
+        matrix.set((0, 2), 2.0 + 1e-12);
+        matrix.set((1, 1), 3.0);
+        let packed =
+            Packed::from_compressed(&matrix, Variant::Lower, Kind::Symmetric, Some(1e-10)).unwrap();
+        assert_eq!(&packed.values, &[1.0, 0.0, 2.0, 3.0, 0.0, 0.0]);
+        assert_eq!(packed.kind, Kind::Symmetric);
+        assert!(
+            Packed::from_compressed(&matrix, Variant::Lower, Kind::Symmetric, Some(1e-14)).is_err()
+        );
+        assert!(
+            Packed::from_compressed(&matrix, Variant::Upper, Kind::Triangular, Some(1e-10))
+                .is_err()
+        );
+        let packed =
+            Packed::from_compressed(&matrix, Variant::Upper, Kind::Triangular, None).unwrap();
+        assert_eq!(&packed.values, &[1.0, 0.0, 3.0, 2.0 + 1e-12, 0.0, 0.0]);
+    }
+
+    #[test]
+    fn from_conventional() {
+        let matrix = Conventional::from_vec(
+            3,
+            matrix![
+                1.0, 2.0, 4.0;
+                0.0, 1.0, 5.0;
+                0.0, 0.0, 1.0;
+            ],
+        );
+        let packed =
+            Packed::from_conventional(&matrix, Variant::Upper, Kind::UnitTriangular, Some(0.0))
+                .unwrap();
+        assert_eq!(Conventional::from(&packed), matrix);
+        assert!(
+            Packed::from_conventional(&matrix, Variant::Lower, Kind::Triangular, Some(0.0))
+                .is_err()
+        );
+        assert!(
+            Packed::from_conventional(&matrix, Variant::Upper, Kind::Symmetric, Some(0.0)).is_err()
+        );
+        let matrix = Conventional::from_vec((2, 3), vec![0.0; 6]);
+        assert!(
+            Packed::from_conventional(&matrix, Variant::Upper, Kind::Triangular, None).is_err()
+        );
+    }
+
+    #[test]
+    fn from_conventional_hermitian() {
+        let mut matrix = Conventional::from_vec(
+            2,
+            vec![
+                c64::new(1.0, 0.0),
+                c64::new(2.0, 3.0),
+                c64::new(2.0, -3.0),
+                c64::new(4.0, 0.0),
+            ],
+        );
+        let packed =
+            Packed::from_conventional(&matrix, Variant::Lower, Kind::Hermitian, Some(0.0)).unwrap();
+        assert_eq!(Conventional::from(&packed), matrix);
+        assert!(
+            Packed::from_conventional(&matrix, Variant::Lower, Kind::Symmetric, Some(0.0)).is_err()
+        );
+        matrix[(1, 1)] = c64::new(4.0, 1.0);
+        assert!(
+            Packed::from_conventional(&matrix, Variant::Lower, Kind::Hermitian, Some(0.5)).is_err()
+        );
+    }
+
+    #[test]
+    fn into_conventional_hermitian() {
+        let matrix = new!(
+            2,
+            Variant::Upper,
+            Kind::Hermitian,
+            vec![c64::new(1.0, 0.0), c64::new(2.0, 3.0), c64::new(4.0, 0.0)]
+        );
+        let matrix = Conventional::from(matrix);
+        assert_eq!(
+            &*matrix,
+            &[
+                c64::new(1.0, 0.0),
+                c64::new(2.0, -3.0),
+                c64::new(2.0, 3.0),
+                c64::new(4.0, 0.0),
+            ]
+        );
+    }
+
+    #[test]
+    fn into_conventional_symmetric() {
+        let matrix = new!(
+            3,
+            Variant::Lower,
+            Kind::Symmetric,
+            vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0]
+        );
+        let matrix = Conventional::from(matrix);
+        assert_eq!(
+            &*matrix,
+            &*matrix![
+                1.0, 2.0, 3.0;
+                2.0, 4.0, 5.0;
+                3.0, 5.0, 6.0;
+            ]
+        );
+    }
+
+    #[test]
+    fn into_conventional_unit_triangular() {
+        let matrix = new!(
+            3,
+            Variant::Upper,
+            Kind::UnitTriangular,
+            vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0]
+        );
+        let matrix = Conventional::from(matrix);
+        assert_eq!(
+            &*matrix,
+            &*matrix![
+                1.0, 2.0, 4.0;
+                0.0, 1.0, 5.0;
+                0.0, 0.0, 1.0;
+            ]
+        );
+    }
+
     #[test]
     fn into_conventional_lower() {
         let matrix = new!(
@@ -0,0 +1,421 @@
+use num_traits::Float;
+
+use decomposition::{Condition, Solve};
+use format::packed::{Kind, Variant};
+use format::Packed;
+use {Element, Result};
+
+/// The Bunch–Kaufman decomposition of a symmetric matrix.
+///
+/// The decomposition has the form `A = P × L × D × Lᵀ × Pᵀ` where `P` is a
+/// permutation, `L` is a unit lower-triangular matrix, and `D` is a
+/// block-diagonal matrix with blocks of size one and two. The decomposition is
+/// computed as done by [`sptrf`][1] in LAPACK.
+///
+/// [1]: http://www.netlib.org/lapack/explore-html/d3/d50/dsptrf_8f.html
+#[derive(Clone, Debug, PartialEq)]
+pub struct BunchKaufman<T: Element> {
+    /// The factors stored in place of the triangle of the original matrix.
+    /// The diagonal blocks hold `D`, and the rest of the lower triangle when
+    /// `variant = Lower` or upper triangle when `variant = Upper` holds the
+    /// multipliers of `L` or their transpose, respectively.
+    pub factors: Packed<T>,
+    /// The pivots.
+    pub pivots: Vec<Pivot>,
+}
+
+/// A pivot of the Bunch–Kaufman decomposition.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Pivot {
+    /// A diagonal block of size one whose row and column were interchanged
+    /// with the specified ones.
+    Single(usize),
+    /// A diagonal block of size two whose second row and column were
+    /// interchanged with the specified ones. The pivot is recorded for both
+    /// rows of the block.
+    Double(usize),
+}
+
+macro_rules! at(
+    ($size:expr, $variant:expr, $i:expr, $j:expr) => ({
+        let (i, j) = ($i, $j);
+        debug_assert!(i >= j);
+        match $variant {
+            Variant::Lower => offset!($size, Variant::Lower, j) + i - j,
+            Variant::Upper => offset!($size, Variant::Upper, i) + j,
+        }
+    });
+);
+
+impl<T> Packed<T>
+where
+    T: Element + Float,
+{
+    /// Perform the Bunch–Kaufman decomposition.
+    ///
+    /// The matrix should be symmetric. Singular matrices are decomposed as
+    /// well, in which case `D` has zero blocks.
+    pub fn bunch_kaufman(&self) -> Result<BunchKaufman<T>> {
+        if let Kind::Triangular | Kind::UnitTriangular = self.kind {
+            raise!("the Bunch–Kaufman decomposition requires a symmetric matrix");
+        }
+        let mut factors = validate!(self).clone();
+        let pivots = factorize(&mut factors);
+        Ok(BunchKaufman { factors, pivots })
+    }
+}
+
+impl<T> BunchKaufman<T>
+where
+    T: Element + Float,
+{
+    /// Compute the determinant.
+    pub fn determinant(&self) -> T {
+        let mut product: T = Element::one();
+        self.blocks(|a, b, c| match b {
+            Some(b) => product = product * (a * c.unwrap() - b * b),
+            _ => product = product * a,
+        });
+        product
+    }
+
+    /// Compute the inertia, which is the numbers of positive, negative, and
+    /// zero eigenvalues.
+    pub fn inertia(&self) -> (usize, usize, usize) {
+        let (mut positive, mut negative, mut zero) = (0, 0, 0);
+        {
+            let mut count = |value: T| {
+                if value > Element::zero() {
+                    positive += 1;
+                } else if value < Element::zero() {
+                    negative += 1;
+                } else {
+                    zero += 1;
+                }
+            };
+            self.blocks(|a, b, c| match b {
+                Some(b) => {
+                    let c = c.unwrap();
+                    let determinant = a * c - b * b;
+                    if determinant < Element::zero() {
+                        count(Element::one());
+                        count(-<T as Element>::one());
+                    } else if determinant > Element::zero() {
+                        count(a + c);
+                        count(a + c);
+                    } else {
+                        count(a + c);
+                        count(Element::zero());
+                    }
+                }
+                _ => count(a),
+            });
+        }
+        (positive, negative, zero)
+    }
+
+    // Call a function for each diagonal block `[a, b; b, c]` of `D`, where `b`
+    // and `c` are absent for blocks of size one.
+    fn blocks<F>(&self, mut callback: F)
+    where
+        F: FnMut(T, Option<T>, Option<T>),
+    {
+        let &Packed {
+            size: n,
+            variant,
+            ref values,
+            ..
+        } = &self.factors;
+        let mut k = 0;
+        while k < n {
+            match self.pivots[k] {
+                Pivot::Single(_) => {
+                    callback(values[at!(n, variant, k, k)], None, None);
+                    k += 1;
+                }
+                Pivot::Double(_) => {
+                    callback(
+                        values[at!(n, variant, k, k)],
+                        Some(values[at!(n, variant, k + 1, k)]),
+                        Some(values[at!(n, variant, k + 1, k + 1)]),
+                    );
+                    k += 2;
+                }
+            }
+        }
+    }
+}
+
+impl<T> Condition<T> for BunchKaufman<T>
+where
+    T: Element + Float,
+{
+    #[inline]
+    fn order(&self) -> usize {
+        self.factors.size
+    }
+}
+
+impl<T> Solve<T> for BunchKaufman<T>
+where
+    T: Element + Float,
+{
+    fn solve(&self, right: &mut [T]) {
+        let &Packed {
+            size: n,
+            variant,
+            ref values,
+            ..
+        } = &self.factors;
+        if n == 0 {
+            return;
+        }
+        debug_assert_eq!(right.len() % n, 0);
+        macro_rules! a(($i:expr, $j:expr) => (values[at!(n, variant, $i, $j)]));
+        for b in right.chunks_mut(n) {
+            let mut k = 0;
+            while k < n {
+                match self.pivots[k] {
+                    Pivot::Single(p) => {
+                        b.swap(k, p);
+                        let value = b[k];
+                        for i in (k + 1)..n {
+                            b[i] = b[i] - a!(i, k) * value;
+                        }
+                        b[k] = value / a!(k, k);
+                        k += 1;
+                    }
+                    Pivot::Double(p) => {
+                        b.swap(k + 1, p);
+                        let (first, second) = (b[k], b[k + 1]);
+                        for i in (k + 2)..n {
+                            b[i] = b[i] - a!(i, k) * first - a!(i, k + 1) * second;
+                        }
+                        let offdiagonal = a!(k + 1, k);
+                        let (d11, d22) = (a!(k, k) / offdiagonal, a!(k + 1, k + 1) / offdiagonal);
+                        let denominator = d11 * d22 - Element::one();
+                        let (first, second) = (first / offdiagonal, second / offdiagonal);
+                        b[k] = (d22 * first - second) / denominator;
+                        b[k + 1] = (d11 * second - first) / denominator;
+                        k += 2;
+                    }
+                }
+            }
+            let mut k = n;
+            while k > 0 {
+                let (pivot, size) = match self.pivots[k - 1] {
+                    Pivot::Single(p) => (p, 1),
+                    Pivot::Double(p) => (p, 2),
+                };
+                for j in (k - size)..k {
+                    let mut value = b[j];
+                    for i in k..n {
+                        value = value - a!(i, j) * b[i];
+                    }
+                    b[j] = value;
+                }
+                b.swap(k - 1, pivot);
+                k -= size;
+            }
+        }
+    }
+
+    #[inline]
+    fn solve_transpose(&self, right: &mut [T]) {
+        self.solve(right)
+    }
+}
+
+fn factorize<T>(matrix: &mut Packed<T>) -> Vec<Pivot>
+where
+    T: Element + Float,
+{
+    let &mut Packed {
+        size: n,
+        variant,
+        ref mut values,
+        ..
+    } = matrix;
+    macro_rules! a(($i:expr, $j:expr) => (values[at!(n, variant, $i, $j)]));
+    let alpha = (<T as Element>::one() + T::from(17.0).unwrap().sqrt()) / T::from(8.0).unwrap();
+    let mut pivots = Vec::with_capacity(n);
+    let mut k = 0;
+    while k < n {
+        let mut double = false;
+        let diagonal = a!(k, k).abs();
+        let (mut r, mut column) = (k, <T as Element>::zero());
+        for i in (k + 1)..n {
+            if a!(i, k).abs() > column {
+                r = i;
+                column = a!(i, k).abs();
+            }
+        }
+        let mut p = k;
+        if diagonal.max(column) > Element::zero() && diagonal < alpha * column {
+            let mut row: T = Element::zero();
+            for j in k..n {
+                if j != r {
+                    let value = if j < r { a!(r, j) } else { a!(j, r) };
+                    row = row.max(value.abs());
+                }
+            }
+            if diagonal < alpha * column * (column / row) {
+                if a!(r, r).abs() >= alpha * row {
+                    p = r;
+                } else {
+                    p = r;
+                    double = true;
+                }
+            }
+        }
+        let l = if double { k + 1 } else { k };
+        if p != l {
+            for i in (p + 1)..n {
+                values.swap(at!(n, variant, i, l), at!(n, variant, i, p));
+            }
+            for j in (l + 1)..p {
+                values.swap(at!(n, variant, j, l), at!(n, variant, p, j));
+            }
+            values.swap(at!(n, variant, l, l), at!(n, variant, p, p));
+            if double {
+                values.swap(at!(n, variant, k + 1, k), at!(n, variant, p, k));
+            }
+        }
+        if double {
+            if k + 2 < n {
+                let offdiagonal = a!(k + 1, k);
+                let d11 = a!(k + 1, k + 1) / offdiagonal;
+                let d22 = a!(k, k) / offdiagonal;
+                let scale = <T as Element>::one() / (d11 * d22 - Element::one()) / offdiagonal;
+                for j in (k + 2)..n {
+                    let first = scale * (d11 * a!(j, k) - a!(j, k + 1));
+                    let second = scale * (d22 * a!(j, k + 1) - a!(j, k));
+                    for i in j..n {
+                        a!(i, j) = a!(i, j) - a!(i, k) * first - a!(i, k + 1) * second;
+                    }
+                    a!(j, k) = first;
+                    a!(j, k + 1) = second;
+                }
+            }
+            pivots.push(Pivot::Double(p));
+            pivots.push(Pivot::Double(p));
+            k += 2;
+        } else {
+            let pivot = a!(k, k);
+            if !Element::is_zero(&pivot) {
+                for j in (k + 1)..n {
+                    let factor = a!(j, k) / pivot;
+                    for i in j..n {
+                        a!(i, j) = a!(i, j) - a!(i, k) * factor;
+                    }
+                }
+                for i in (k + 1)..n {
+                    a!(i, k) = a!(i, k) / pivot;
+                }
+            }
+            pivots.push(Pivot::Single(p));
+            k += 1;
+        }
+    }
+    pivots
+}
+
+#[cfg(test)]
+mod tests {
+    use assert;
+    use format::packed::{Kind, Pivot, Variant};
+    use prelude::*;
+
+    #[test]
+    fn bunch_kaufman() {
+        let matrix = Packed::from_conventional(
+            &Conventional::from_vec(
+                2,
+                matrix![
+                    0.0, 1.0;
+                    1.0, 0.0;
+                ],
+            ),
+            Variant::Lower,
+            Kind::Symmetric,
+            None,
+        )
+        .unwrap();
+        let decomposition = matrix.bunch_kaufman().unwrap();
+        assert_eq!(&decomposition.pivots, &[Pivot::Double(1), Pivot::Double(1)]);
+        assert_eq!(decomposition.inertia(), (1, 1, 0));
+        assert_eq!(decomposition.determinant(), -1.0);
+        assert!(Packed::<f64>::new(2, Variant::Lower)
+            .bunch_kaufman()
+            .is_err());
+    }
+
+    #[test]
+    fn determinant() {
+        let matrix = Conventional::from_vec(
+            5,
+            matrix![
+                4.0,  1.0,  0.0,  2.0,  1.0;
+                1.0,  0.0,  0.0,  1.0,  0.0;
+                0.0,  0.0,  0.0,  3.0, -1.0;
+                2.0,  1.0,  3.0, -2.0,  0.0;
+                1.0,  0.0, -1.0,  0.0,  1.0;
+            ],
+        );
+        for &variant in &[Variant::Lower, Variant::Upper] {
+            let matrix =
+                Packed::from_conventional(&matrix, variant, Kind::Symmetric, Some(0.0)).unwrap();
+            let decomposition = matrix.bunch_kaufman().unwrap();
+            assert::close(decomposition.determinant(), 1.0, 1e-12);
+        }
+    }
+
+    #[test]
+    fn inertia() {
+        let matrix = Conventional::from_vec(
+            5,
+            matrix![
+                4.0,  1.0,  0.0,  2.0,  1.0;
+                1.0,  0.0,  0.0,  1.0,  0.0;
+                0.0,  0.0,  0.0,  3.0, -1.0;
+                2.0,  1.0,  3.0, -2.0,  0.0;
+                1.0,  0.0, -1.0,  0.0,  1.0;
+            ],
+        );
+        for &variant in &[Variant::Lower, Variant::Upper] {
+            let matrix =
+                Packed::from_conventional(&matrix, variant, Kind::Symmetric, Some(0.0)).unwrap();
+            let decomposition = matrix.bunch_kaufman().unwrap();
+            assert_eq!(decomposition.inertia(), (3, 2, 0));
+        }
+        let mut matrix = Packed::new(3, Variant::Upper);
+        matrix.kind = Kind::Symmetric;
+        matrix.set((0, 0), 1.0);
+        matrix.set((2, 2), -1.0);
+        assert_eq!(matrix.bunch_kaufman().unwrap().inertia(), (1, 1, 1));
+    }
+
+    #[test]
+    fn solve() {
+        let matrix = Conventional::from_vec(
+            5,
+            matrix![
+                4.0,  1.0,  0.0,  2.0,  1.0;
+                1.0,  0.0,  0.0,  1.0,  0.0;
+                0.0,  0.0,  0.0,  3.0, -1.0;
+                2.0,  1.0,  3.0, -2.0,  0.0;
+                1.0,  0.0, -1.0,  0.0,  1.0;
+            ],
+        );
+        for &variant in &[Variant::Lower, Variant::Upper] {
+            let matrix =
+                Packed::from_conventional(&matrix, variant, Kind::Symmetric, Some(0.0)).unwrap();
+            let decomposition = matrix.bunch_kaufman().unwrap();
+            let expected = vec![1.0, -2.0, 3.0, -4.0, 5.0, 2.0, 1.0, 0.0, -1.0, 3.0];
+            let mut right = vec![0.0; 10];
+            matrix.multiply_into(&expected, &mut right);
+            decomposition.solve(&mut right);
+            assert::close(&right, &expected, 1e-12);
+        }
+    }
+}
@@ -1,12 +1,17 @@
 //! The packed format.
 //!
 //! The format is suitable for symmetric, Hermitian, and triangular matrices.
-//! The format is compatible with the [one][1] adopted by [LAPACK][2].
+//! The format is compatible with the [one][1] adopted by [LAPACK][2]. The
+//! interpretation of the triangle that is not stored is determined by the kind
+//! of the matrix.
 //!
 //! [1]: http://www.netlib.org/lapack/lug/node123.html
 //! [2]: http://www.netlib.org/lapack
 
-use {Element, Matrix, Size};
+use std::{iter, slice};
+
+use format::{product, sum};
+use {Element, Matrix, Position, Result, Size};
 
 /// A packed matrix.
 #[derive(Clone, Debug, PartialEq)]
@@ -15,6 +20,8 @@ pub struct Packed<T: Element> {
     pub size: usize,
     /// The format variant.
     pub variant: Variant,
+    /// The matrix kind.
+    pub kind: Kind,
     /// The values of the lower triangle when `variant = Lower` or upper
     /// triangle when `variant = Upper` stored by columns.
     pub values: Vec<T>,
@@ -22,7 +29,10 @@ pub struct Packed<T: Element> {
 
 macro_rules! new(
     ($size:expr, $variant:expr, $values:expr) => (
-        Packed { size: $size, variant: $variant, values: $values }
+        new!($size, $variant, Kind::Triangular, $values)
+    );
+    ($size:expr, $variant:expr, $kind:expr, $values:expr) => (
+        Packed { size: $size, variant: $variant, kind: $kind, values: $values }
     );
 );
 
@@ -36,9 +46,31 @@ macro_rules! storage(
     ($size:expr) => (arithmetic!($size, 1, $size))
 );
 
+macro_rules! first(
+    ($variant:expr, $column:expr) => (
+        match $variant {
+            Variant::Lower => $column,
+            Variant::Upper => 0,
+        }
+    );
+);
+
+macro_rules! offset(
+    ($size:expr, $variant:expr, $column:expr) => ({
+        let (size, column) = ($size, $column);
+        match $variant {
+            Variant::Lower => arithmetic!(column, size, size - column + 1),
+            Variant::Upper => arithmetic!(column, 1, column),
+        }
+    });
+);
+
 mod convert;
+mod decomposition;
 mod operation;
 
+pub use self::decomposition::{BunchKaufman, Pivot};
+
 /// A variant of a packed matrix.
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
 pub enum Variant {
@@ -48,6 +80,41 @@ pub enum Variant {
     Upper,
 }
 
+/// A kind of a packed matrix.
+///
+/// The kind determines the interpretation of the triangle that is not stored.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Kind {
+    /// A triangular matrix, whose other triangle is zero.
+    Triangular,
+    /// A triangular matrix with ones on the diagonal, whose other triangle is
+    /// zero. The stored diagonal elements are not referenced.
+    UnitTriangular,
+    /// A symmetric matrix, whose other triangle mirrors the stored one.
+    Symmetric,
+    /// A Hermitian matrix, whose other triangle mirrors the stored one and is
+    /// conjugated.
+    Hermitian,
+}
+
+/// A sparse iterator.
+pub struct Iterator<'l, T: 'l + Element> {
+    size: usize,
+    variant: Variant,
+    row: usize,
+    column: usize,
+    values: slice::Iter<'l, T>,
+}
+
+/// A sparse iterator allowing mutation.
+pub struct IteratorMut<'l, T: 'l + Element> {
+    size: usize,
+    variant: Variant,
+    row: usize,
+    column: usize,
+    values: slice::IterMut<'l, T>,
+}
+
 #[cfg(debug_assertions)]
 impl<T: Element> ::format::Validate for Packed<T> {
     fn validate(&self) {
@@ -58,21 +125,143 @@ impl<T: Element> ::format::Validate for Packed<T> {
 size!(Packed, size, size);
 
 impl<T: Element> Packed<T> {
-    /// Create a zero matrix.
+    /// Create a zero triangular matrix.
     pub fn new<S: Size>(size: S, variant: Variant) -> Self {
         let (rows, _columns) = size.dimensions();
         debug_assert!(rows == _columns);
         new!(rows, variant, vec![T::zero(); storage!(rows)])
     }
+
+    /// Create a zero triangular matrix checking that the size is square.
+    pub fn try_new<S: Size>(size: S, variant: Variant) -> Result<Self> {
+        let size = square(size)?;
+        Ok(new!(size, variant, vec![T::zero(); capacity(size)?]))
+    }
+
+    /// Create a triangular matrix from a vector checking that the size is
+    /// square and that the vector has exactly `size × (size + 1) / 2`
+    /// elements.
+    #[inline]
+    pub fn try_from_vec<S: Size>(size: S, variant: Variant, values: Vec<T>) -> Result<Self> {
+        Packed::from_parts(size, variant, Kind::Triangular, values)
+    }
+
+    /// Create a matrix from its parts checking that the size is square and
+    /// that the vector has exactly `size × (size + 1) / 2` elements.
+    pub fn from_parts<S: Size>(
+        size: S,
+        variant: Variant,
+        kind: Kind,
+        values: Vec<T>,
+    ) -> Result<Self> {
+        let size = square(size)?;
+        let expected = capacity(size)?;
+        if values.len() != expected {
+            raise!(format!(
+                "a packed matrix of size {} requires {} values but {} have been given",
+                size,
+                expected,
+                values.len()
+            ));
+        }
+        Ok(new!(size, variant, kind, values))
+    }
+
+    /// Read an element.
+    ///
+    /// The element is interpreted according to the kind of the matrix.
+    pub fn get<P: Position>(&self, position: P) -> T {
+        let (i, j) = position.coordinates();
+        debug_assert!(i < self.size && j < self.size);
+        match (self.kind, self.position(i, j), self.position(j, i)) {
+            (Kind::UnitTriangular, _, _) if i == j => T::one(),
+            (_, Some(k), _) => self.values[k],
+            (Kind::Symmetric, _, Some(k)) => self.values[k],
+            (Kind::Hermitian, _, Some(k)) => self.values[k].conj(),
+            _ => T::zero(),
+        }
+    }
+
+    /// Assign a value to an element.
+    ///
+    /// For symmetric and Hermitian matrices, a position outside the stored
+    /// triangle refers to its mirror image. The function panics when the
+    /// position lies outside the stored triangle of a triangular matrix or on
+    /// the diagonal of a unit triangular one.
+    pub fn set<P: Position>(&mut self, position: P, value: T) {
+        let (i, j) = position.coordinates();
+        debug_assert!(i < self.size && j < self.size);
+        match (self.kind, self.position(i, j), self.position(j, i)) {
+            (Kind::UnitTriangular, _, _) if i == j => {
+                panic!("the diagonal of a unit triangular matrix cannot be assigned")
+            }
+            (_, Some(k), _) => self.values[k] = value,
+            (Kind::Symmetric, _, Some(k)) => self.values[k] = value,
+            (Kind::Hermitian, _, Some(k)) => self.values[k] = value.conj(),
+            _ => panic!(
+                "the position ({}, {}) lies outside the stored triangle",
+                i, j
+            ),
+        }
+    }
+
+    /// Return a sparse iterator over the stored elements.
+    #[inline]
+    pub fn iter<'l>(&'l self) -> Iterator<'l, T> {
+        Iterator {
+            size: self.size,
+            variant: self.variant,
+            row: first!(self.variant, 0),
+            column: 0,
+            values: self.values.iter(),
+        }
+    }
+
+    /// Return a sparse iterator over the stored elements allowing mutation.
+    #[inline]
+    pub fn iter_mut<'l>(&'l mut self) -> IteratorMut<'l, T> {
+        IteratorMut {
+            size: self.size,
+            variant: self.variant,
+            row: first!(self.variant, 0),
+            column: 0,
+            values: self.values.iter_mut(),
+        }
+    }
+
+    // Store the unit diagonal of a unit triangular matrix explicitly and make
+    // the matrix triangular.
+    pub(super) fn materialize(&mut self) {
+        if let Kind::UnitTriangular = self.kind {
+            self.kind = Kind::Triangular;
+            for (i, j, value) in self.iter_mut() {
+                if i == j {
+                    *value = T::one();
+                }
+            }
+        }
+    }
+
+    fn position(&self, i: usize, j: usize) -> Option<usize> {
+        match self.variant {
+            Variant::Lower if i >= j => Some(offset!(self.size, Variant::Lower, j) + i - j),
+            Variant::Upper if i <= j => Some(offset!(self.size, Variant::Upper, j) + i),
+            _ => None,
+        }
+    }
 }
 
 impl<T: Element> Matrix for Packed<T> {
     type Element = T;
 
     fn nonzeros(&self) -> usize {
-        self.values
-            .iter()
-            .fold(0, |sum, &value| if value.is_zero() { sum } else { sum + 1 })
+        let kind = self.kind;
+        self.iter().fold(0, |sum, (i, j, value)| match kind {
+            Kind::UnitTriangular if i == j => sum + 1,
+            _ if value.is_zero() => sum,
+            Kind::Symmetric | Kind::Hermitian if i != j => sum + 2,
+            _ => sum + 1,
+        })
     }
 
     #[inline]
@@ -92,9 +281,55 @@ impl Variant {
     }
 }
 
+macro_rules! iterator(
+    (struct $name:ident -> $item:ty) => (
+        impl<'l, T: Element> iter::Iterator for $name<'l, T> {
+            type Item = $item;
+
+            fn next(&mut self) -> Option<Self::Item> {
+                let value = self.values.next()?;
+                let (i, j) = (self.row, self.column);
+                let last = match self.variant {
+                    Variant::Lower => i + 1 == self.size,
+                    Variant::Upper => i == j,
+                };
+                if last {
+                    self.column += 1;
+                    self.row = first!(self.variant, self.column);
+                } else {
+                    self.row += 1;
+                }
+                Some((i, j, value))
+            }
+        }
+    );
+);
+
+iterator!(struct Iterator -> (usize, usize, &'l T));
+iterator!(struct IteratorMut -> (usize, usize, &'l mut T));
+
+fn square<S: Size>(size: S) -> Result<usize> {
+    let (rows, columns) = size.dimensions();
+    if rows != columns {
+        raise!(format!(
+            "a packed matrix should be square but is {} × {}",
+            rows, columns
+        ));
+    }
+    Ok(rows)
+}
+
+fn capacity(size: usize) -> Result<usize> {
+    match size % 2 {
+        0 => product(size / 2, sum(size, 1)?),
+        _ => product(size, sum(size, 1)? / 2),
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use format::packed::Variant;
+    use c64;
+    use format::packed::{Kind, Variant};
     use prelude::*;
 
     #[test]
@@ -106,4 +341,99 @@ mod tests {
         );
         assert_eq!(matrix.nonzeros(), 7);
     }
+
+    #[test]
+    fn nonzeros_kind() {
+        let mut matrix = new!(3, Variant::Upper, vec![0.0, 2.0, 0.0, 4.0, 0.0, 6.0]);
+        assert_eq!(matrix.nonzeros(), 3);
+        matrix.kind = Kind::UnitTriangular;
+        assert_eq!(matrix.nonzeros(), 5);
+        matrix.kind = Kind::Symmetric;
+        assert_eq!(matrix.nonzeros(), 5);
+    }
+
+    #[test]
+    fn get_kind() {
+        let mut matrix = new!(
+            2,
+            Variant::Lower,
+            Kind::Hermitian,
+            vec![c64::new(1.0, 0.0), c64::new(2.0, 3.0), c64::new(4.0, 0.0)]
+        );
+        assert_eq!(matrix.get((1, 0)), c64::new(2.0, 3.0));
+        assert_eq!(matrix.get((0, 1)), c64::new(2.0, -3.0));
+        matrix.set((0, 1), c64::new(5.0, 6.0));
+        assert_eq!(matrix.values[1], c64::new(5.0, -6.0));
+        matrix.kind = Kind::Symmetric;
+        assert_eq!(matrix.get((0, 1)), c64::new(5.0, -6.0));
+        matrix.kind = Kind::UnitTriangular;
+        assert_eq!(matrix.get((0, 1)), c64::new(0.0, 0.0));
+        assert_eq!(matrix.get((1, 1)), c64::new(1.0, 0.0));
+    }
+
+    #[test]
+    fn get() {
+        let matrix = new!(3, Variant::Lower, vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
+        assert_eq!(matrix.get((0, 0)), 1.0);
+        assert_eq!(matrix.get((2, 0)), 3.0);
+        assert_eq!(matrix.get((2, 1)), 5.0);
+        assert_eq!(matrix.get((1, 2)), 0.0);
+        let matrix = new!(3, Variant::Upper, vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
+        assert_eq!(matrix.get((0, 1)), 2.0);
+        assert_eq!(matrix.get((1, 2)), 5.0);
+        assert_eq!(matrix.get((2, 2)), 6.0);
+        assert_eq!(matrix.get((2, 1)), 0.0);
+    }
+
+    #[test]
+    fn set() {
+        for &variant in &[Variant::Lower, Variant::Upper] {
+            let mut matrix = Packed::new(3, variant);
+            for (i, j, _) in matrix.clone().iter() {
+                matrix.set((i, j), (10 * i + j) as f64);
+            }
+            for (i, j, &value) in matrix.iter() {
+                assert_eq!(value, (10 * i + j) as f64);
+            }
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn set_outside() {
+        let mut matrix = Packed::new(3, Variant::Upper);
+        matrix.set((2, 1), 1.0);
+    }
+
+    #[test]
+    fn iter() {
+        let matrix = new!(3, Variant::Lower, vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
+        assert_eq!(
+            matrix.iter().map(|(i, j, _)| (i, j)).collect::<Vec<_>>(),
+            vec![(0, 0), (1, 0), (2, 0), (1, 1), (2, 1), (2, 2)]
+        );
+        let matrix = new!(3, Variant::Upper, vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
+        assert_eq!(
+            matrix.iter().map(|(i, j, _)| (i, j)).collect::<Vec<_>>(),
+            vec![(0, 0), (0, 1), (1, 1), (0, 2), (1, 2), (2, 2)]
+        );
+    }
+
+    #[test]
+    fn iter_mut() {
+        let mut matrix = Packed::new(3, Variant::Upper);
+        for (i, j, value) in matrix.iter_mut() {
+            *value = (i + j) as f64;
+        }
+        assert_eq!(&matrix.values, &[0.0, 1.0, 2.0, 2.0, 3.0, 4.0]);
+    }
+
+    #[test]
+    fn try_from_vec() {
+        let matrix = Packed::try_from_vec(3, Variant::Upper, vec![1.0; 6]).unwrap();
+        assert_eq!(matrix, new!(3, Variant::Upper, vec![1.0; 6]));
+        assert!(Packed::try_from_vec(3, Variant::Upper, vec![1.0; 5]).is_err());
+        assert!(Packed::try_from_vec((3, 2), Variant::Lower, vec![1.0; 6]).is_err());
+        assert!(Packed::<f64>::try_new(usize::max_value(), Variant::Lower).is_err());
+    }
 }
@@ -1,13 +1,224 @@
-use format::packed::Variant;
-use format::Packed;
-use operation::Transpose;
-use Element;
+use format::multiply::Columns;
+use format::packed::{Kind, Variant};
+use format::{Diagonal, Packed};
+use operation::{Multiply, MultiplyInto, Transpose};
+use {Element, Number, Result};
 
+/// The product of a diagonal and a triangular matrix.
+///
+/// The result is triangular with the variant of the right operand. The
+/// function panics when the right operand is symmetric or Hermitian.
+impl<T> Multiply<Packed<T>, Packed<T>> for Diagonal<T>
+where
+    T: Element + Number,
+{
+    fn multiply(&self, right: &Packed<T>) -> Packed<T> {
+        debug_assert_eq!((self.rows, self.columns), (right.size, right.size));
+        let mut result = triangular(right);
+        for (i, _, value) in result.iter_mut() {
+            *value = self.values[i] * *value;
+        }
+        result
+    }
+}
+
+/// The product of a triangular and a diagonal matrix.
+///
+/// The result is triangular with the variant of the left operand. The
+/// function panics when the left operand is symmetric or Hermitian.
+impl<T> Multiply<Diagonal<T>, Packed<T>> for Packed<T>
+where
+    T: Element + Number,
+{
+    fn multiply(&self, right: &Diagonal<T>) -> Packed<T> {
+        debug_assert_eq!((self.size, self.size), (right.rows, right.columns));
+        let mut result = triangular(self);
+        for (_, j, value) in result.iter_mut() {
+            *value = *value * right.values[j];
+        }
+        result
+    }
+}
+
+/// The product of two triangular matrices.
+///
+/// The result is triangular with the variant of the operands. The function
+/// panics when the operands are symmetric or Hermitian or have different
+/// variants.
+impl<T> Multiply<Packed<T>, Packed<T>> for Packed<T>
+where
+    T: Element + Number,
+{
+    fn multiply(&self, right: &Packed<T>) -> Packed<T> {
+        debug_assert_eq!(self.size, right.size);
+        let (a, b) = (triangular(self), triangular(right));
+        if a.variant != b.variant {
+            panic!(
+                "the product of {:?} and {:?} triangular matrices is not triangular",
+                a.variant, b.variant
+            );
+        }
+        let (n, variant) = (a.size, a.variant);
+        let mut result = new!(n, variant, vec![Element::zero(); a.values.len()]);
+        let c = &mut result.values;
+        for j in 0..n {
+            let column = offset!(n, variant, j);
+            match variant {
+                Variant::Lower => {
+                    for k in j..n {
+                        let factor = b.values[column + k - j];
+                        if Element::is_zero(&factor) {
+                            continue;
+                        }
+                        let offset = offset!(n, variant, k);
+                        for i in k..n {
+                            c[column + i - j] =
+                                c[column + i - j] + a.values[offset + i - k] * factor;
+                        }
+                    }
+                }
+                Variant::Upper => {
+                    for k in 0..(j + 1) {
+                        let factor = b.values[column + k];
+                        if Element::is_zero(&factor) {
+                            continue;
+                        }
+                        let offset = offset!(n, variant, k);
+                        for i in 0..(k + 1) {
+                            c[column + i] = c[column + i] + a.values[offset + i] * factor;
+                        }
+                    }
+                }
+            }
+        }
+        result
+    }
+}
+
+impl<T> MultiplyInto<[T], [T]> for Packed<T>
+where
+    T: Element + Number,
+{
+    #[inline]
+    fn multiply_into(&self, right: &[T], result: &mut [T]) {
+        let n = right.len() / self.size;
+        multiply_matrix_left(self, right, result, self.size, n)
+    }
+}
+
+impl<T> MultiplyInto<Vec<T>, [T]> for Packed<T>
+where
+    T: Element + Number,
+{
+    #[inline(always)]
+    fn multiply_into(&self, right: &Vec<T>, result: &mut [T]) {
+        MultiplyInto::multiply_into(self, right as &[T], result)
+    }
+}
+
+// The stored part of a column is read directly, and the mirrored part of a
+// symmetric or Hermitian matrix is read along the corresponding row by a
+// running offset.
+impl<T: Element> Columns<T> for Packed<T> {
+    fn column<F>(&self, j: usize, mut callback: F)
+    where
+        F: FnMut(usize, T),
+    {
+        let &Packed {
+            size: n,
+            variant,
+            kind,
+            ref values,
+        } = self;
+        let mirror = kind == Kind::Symmetric || kind == Kind::Hermitian;
+        let read = |value: T| {
+            if kind == Kind::Hermitian {
+                value.conj()
+            } else {
+                value
+            }
+        };
+        let diagonal = |value: T| {
+            if kind == Kind::UnitTriangular {
+                T::one()
+            } else {
+                value
+            }
+        };
+        let column = offset!(n, variant, j);
+        match variant {
+            Variant::Lower => {
+                if mirror {
+                    let mut k = j;
+                    for i in 0..j {
+                        callback(i, read(values[k]));
+                        k += n - i - 1;
+                    }
+                }
+                callback(j, diagonal(values[column]));
+                for i in (j + 1)..n {
+                    callback(i, values[column + i - j]);
+                }
+            }
+            Variant::Upper => {
+                for i in 0..j {
+                    callback(i, values[column + i]);
+                }
+                callback(j, diagonal(values[column + j]));
+                if mirror {
+                    let mut k = column + 2 * j + 1;
+                    for i in (j + 1)..n {
+                        callback(i, read(values[k]));
+                        k += i + 1;
+                    }
+                }
+            }
+        }
+    }
+}
+
+impl<T> Packed<T>
+where
+    T: Element + Number,
+{
+    /// Solve `A × X = B` overwriting `B` with `X`.
+    ///
+    /// The right-hand sides are stored in the column-major order. The
+    /// function fails when the matrix is neither triangular nor unit
+    /// triangular.
+    pub fn solve(&self, right: &mut [T]) -> Result<()> {
+        let unit = is_unit(self.kind)?;
+        solve(self, right, unit);
+        Ok(())
+    }
+
+    /// Solve `Aᵀ × X = B` overwriting `B` with `X`.
+    ///
+    /// The right-hand sides are stored in the column-major order. The
+    /// function fails when the matrix is neither triangular nor unit
+    /// triangular.
+    pub fn solve_transpose(&self, right: &mut [T]) -> Result<()> {
+        let unit = is_unit(self.kind)?;
+        solve_transpose(self, right, unit);
+        Ok(())
+    }
+}
+
+/// The transpose.
+///
+/// The kind is preserved. For symmetric and Hermitian matrices, the result
+/// stores the other triangle of the original matrix, which is the transpose
+/// of the stored one.
 impl<T: Element> Transpose for Packed<T> {
     fn transpose(&self) -> Self {
-        let &Packed { size, variant, .. } = self;
+        let &Packed {
+            size,
+            variant,
+            kind,
+            ..
+        } = self;
         let lower = variant == Variant::Lower;
-        let mut matrix = Packed::new(size, variant.flip());
+        let mut matrix = new!(size, variant.flip(), kind, vec![T::zero(); storage!(size)]);
         let mut k = 0;
         for j in 0..size {
             for i in j..size {
@@ -23,11 +234,280 @@ impl<T: Element> Transpose for Packed<T> {
     }
 }
 
+// Return a triangular copy of a triangular or unit triangular matrix.
+fn triangular<T: Element>(matrix: &Packed<T>) -> Packed<T> {
+    match matrix.kind {
+        Kind::Symmetric | Kind::Hermitian => panic!(
+            "the product of a {:?} matrix is not triangular",
+            matrix.kind
+        ),
+        _ => {}
+    }
+    let mut matrix = matrix.clone();
+    matrix.materialize();
+    matrix
+}
+
+fn multiply_matrix_left<T>(a: &Packed<T>, b: &[T], c: &mut [T], m: usize, n: usize)
+where
+    T: Element + Number,
+{
+    debug_assert_eq!(b.len(), m * n);
+    debug_assert_eq!(c.len(), m * n);
+    let kind = a.kind;
+    for k in 0..n {
+        let (b, c) = (&b[(k * m)..((k + 1) * m)], &mut c[(k * m)..((k + 1) * m)]);
+        for (i, j, &value) in a.iter() {
+            if i == j {
+                c[i] = match kind {
+                    Kind::UnitTriangular => c[i] + b[j],
+                    _ => c[i] + value * b[j],
+                };
+                continue;
+            }
+            c[i] = c[i] + value * b[j];
+            match kind {
+                Kind::Symmetric => c[j] = c[j] + value * b[i],
+                Kind::Hermitian => c[j] = c[j] + value.conj() * b[i],
+                _ => {}
+            }
+        }
+    }
+}
+
+fn is_unit(kind: Kind) -> Result<bool> {
+    match kind {
+        Kind::Triangular => Ok(false),
+        Kind::UnitTriangular => Ok(true),
+        _ => {
+            raise!("the solution requires a triangular matrix");
+        }
+    }
+}
+
+fn solve<T>(matrix: &Packed<T>, right: &mut [T], unit: bool)
+where
+    T: Element + Number,
+{
+    let &Packed {
+        size: m,
+        variant,
+        ref values,
+        ..
+    } = matrix;
+    if m == 0 {
+        return;
+    }
+    debug_assert_eq!(right.len() % m, 0);
+    for b in right.chunks_mut(m) {
+        match variant {
+            Variant::Lower => {
+                for j in 0..m {
+                    let offset = offset!(m, Variant::Lower, j) - j;
+                    if !unit {
+                        b[j] = b[j] / values[offset + j];
+                    }
+                    let value = b[j];
+                    for i in (j + 1)..m {
+                        b[i] = b[i] - values[offset + i] * value;
+                    }
+                }
+            }
+            Variant::Upper => {
+                for j in (0..m).rev() {
+                    let offset = offset!(m, Variant::Upper, j);
+                    if !unit {
+                        b[j] = b[j] / values[offset + j];
+                    }
+                    let value = b[j];
+                    for i in 0..j {
+                        b[i] = b[i] - values[offset + i] * value;
+                    }
+                }
+            }
+        }
+    }
+}
+
+fn solve_transpose<T>(matrix: &Packed<T>, right: &mut [T], unit: bool)
+where
+    T: Element + Number,
+{
+    let &Packed {
+        size: m,
+        variant,
+        ref values,
+        ..
+    } = matrix;
+    if m == 0 {
+        return;
+    }
+    debug_assert_eq!(right.len() % m, 0);
+    for b in right.chunks_mut(m) {
+        match variant {
+            Variant::Lower => {
+                for j in (0..m).rev() {
+                    let offset = offset!(m, Variant::Lower, j) - j;
+                    let mut value = b[j];
+                    for i in (j + 1)..m {
+                        value = value - values[offset + i] * b[i];
+                    }
+                    b[j] = if unit {
+                        value
+                    } else {
+                        value / values[offset + j]
+                    };
+                }
+            }
+            Variant::Upper => {
+                for j in 0..m {
+                    let offset = offset!(m, Variant::Upper, j);
+                    let mut value = b[j];
+                    for i in 0..j {
+                        value = value - values[offset + i] * b[i];
+                    }
+                    b[j] = if unit {
+                        value
+                    } else {
+                        value / values[offset + j]
+                    };
+                }
+            }
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use format::packed::Variant;
+    use assert;
+    use c64;
+    use format::multiply::Columns;
+    use format::packed::{Kind, Variant};
     use prelude::*;
 
+    #[test]
+    fn column() {
+        let values = (0..10)
+            .map(|k| c64::new(k as f64 + 1.0, k as f64))
+            .collect::<Vec<_>>();
+        for &variant in &[Variant::Lower, Variant::Upper] {
+            for &kind in &[
+                Kind::Triangular,
+                Kind::UnitTriangular,
+                Kind::Symmetric,
+                Kind::Hermitian,
+            ] {
+                let matrix = new!(4, variant, kind, values.clone());
+                for j in 0..4 {
+                    let mut rows = vec![];
+                    matrix.column(j, |i, value| {
+                        assert_eq!(value, matrix.get((i, j)));
+                        rows.push(i);
+                    });
+                    let expected = match (kind, variant) {
+                        (Kind::Symmetric, _) | (Kind::Hermitian, _) => (0..4).collect(),
+                        (_, Variant::Lower) => (j..4).collect(),
+                        (_, Variant::Upper) => (0..(j + 1)).collect::<Vec<_>>(),
+                    };
+                    assert_eq!(rows, expected);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn multiply_into() {
+        let matrix = new!(3, Variant::Lower, vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
+        let mut result = vec![1.0; 6];
+        matrix.multiply_into(&[1.0, 2.0, 3.0, 1.0, 0.0, 0.0][..], &mut result);
+        assert_eq!(&result, &[2.0, 11.0, 32.0, 2.0, 3.0, 4.0]);
+
+        let matrix = new!(3, Variant::Upper, vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
+        let mut result = vec![0.0; 3];
+        matrix.multiply_into(&[1.0, 2.0, 3.0][..], &mut result);
+        assert_eq!(&result, &[17.0, 21.0, 18.0]);
+    }
+
+    #[test]
+    fn multiply_into_hermitian() {
+        let matrix = new!(
+            2,
+            Variant::Lower,
+            Kind::Hermitian,
+            vec![c64::new(1.0, 0.0), c64::new(2.0, 3.0), c64::new(4.0, 0.0)]
+        );
+        let right = vec![c64::new(1.0, 1.0), c64::new(0.0, 2.0)];
+        let mut result = vec![c64::new(0.0, 0.0); 2];
+        matrix.multiply_into(&right, &mut result);
+        assert_eq!(result, vec![c64::new(7.0, 5.0), c64::new(-1.0, 13.0)]);
+    }
+
+    #[test]
+    fn multiply_into_symmetric() {
+        let expected = [14.0, 25.0, 31.0];
+        for &(variant, ref values) in &[
+            (Variant::Lower, vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0]),
+            (Variant::Upper, vec![1.0, 2.0, 4.0, 3.0, 5.0, 6.0]),
+        ] {
+            let matrix = new!(3, variant, Kind::Symmetric, values.clone());
+            let mut result = vec![0.0; 3];
+            matrix.multiply_into(&[1.0, 2.0, 3.0][..], &mut result);
+            assert_eq!(&result, &expected);
+        }
+    }
+
+    #[test]
+    fn multiply_into_unit_triangular() {
+        let matrix = new!(
+            3,
+            Variant::Lower,
+            Kind::UnitTriangular,
+            vec![9.0, 2.0, 3.0, 9.0, 5.0, 9.0]
+        );
+        let mut result = vec![0.0; 3];
+        matrix.multiply_into(&[1.0, 2.0, 3.0][..], &mut result);
+        assert_eq!(&result, &[1.0, 4.0, 16.0]);
+    }
+
+    #[test]
+    fn solve() {
+        let kinds = [Kind::Triangular, Kind::UnitTriangular];
+        for (&variant, &kind) in [Variant::Lower, Variant::Upper].iter().zip(&kinds) {
+            let matrix = new!(3, variant, kind, vec![2.0, 1.0, 3.0, -1.0, 4.0, 5.0]);
+            let expected = vec![1.0, -2.0, 3.0, 0.5, 2.0, -1.0];
+            let mut right = vec![0.0; 6];
+            matrix.multiply_into(&expected, &mut right);
+            matrix.solve(&mut right).unwrap();
+            assert::close(&right, &expected, 1e-14);
+
+            let mut right = vec![0.0; 6];
+            matrix.transpose().multiply_into(&expected, &mut right);
+            matrix.solve_transpose(&mut right).unwrap();
+            assert::close(&right, &expected, 1e-14);
+        }
+
+        let matrix = new!(2, Variant::Lower, Kind::Symmetric, vec![1.0, 2.0, 3.0]);
+        assert!(matrix.solve(&mut [1.0, 1.0]).is_err());
+        assert!(matrix.solve_transpose(&mut [1.0, 1.0]).is_err());
+    }
+
+    #[test]
+    fn transpose_hermitian() {
+        let matrix = new!(
+            2,
+            Variant::Lower,
+            Kind::Hermitian,
+            vec![c64::new(1.0, 0.0), c64::new(2.0, 3.0), c64::new(4.0, 0.0)]
+        );
+        let transpose = matrix.transpose();
+        assert_eq!(
+            (transpose.variant, transpose.kind),
+            (Variant::Upper, Kind::Hermitian)
+        );
+        assert_eq!(transpose.get((0, 1)), matrix.get((1, 0)));
+        assert_eq!(transpose.get((1, 0)), matrix.get((0, 1)));
+    }
+
     #[test]
     fn transpose_lower() {
         let matrix = new!(
@@ -0,0 +1,338 @@
+//! Reductions.
+//!
+//! The reductions visit only the stored elements of a matrix and account for
+//! implicit zeros where they matter, which is the case for the minima and
+//! maxima.
+
+use num_traits::FromPrimitive;
+
+use format::packed::Kind;
+use format::{Banded, Compressed, Conventional, Diagonal, Packed};
+use {Element, Number, Size};
+
+/// The direction of a reduction.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Axis {
+    /// Reduce each row to a single value.
+    Row,
+    /// Reduce each column to a single value.
+    Column,
+}
+
+// A matrix whose nonzero elements can be enumerated.
+//
+// Each position is visited at most once, and the positions that are not
+// visited are zero.
+trait Entries<T>: Size {
+    fn entries<F>(&self, callback: F)
+    where
+        F: FnMut(usize, usize, T);
+}
+
+macro_rules! reduction(
+    ($kind:ident) => (
+        impl<T: Element> $kind<T> {
+            /// Compute the Frobenius norm.
+            pub fn norm_frobenius(&self) -> f64 {
+                let mut sum = 0.0;
+                self.entries(|_, _, value| {
+                    let value = value.magnitude();
+                    sum += value * value;
+                });
+                sum.sqrt()
+            }
+
+            /// Compute the 1-norm, which is the maximum absolute column sum.
+            #[inline]
+            pub fn norm_one(&self) -> f64 {
+                norm(self, Axis::Column)
+            }
+
+            /// Compute the ∞-norm, which is the maximum absolute row sum.
+            #[inline]
+            pub fn norm_infinity(&self) -> f64 {
+                norm(self, Axis::Row)
+            }
+
+            /// Compute the max-abs norm, which is the largest magnitude of the
+            /// elements.
+            pub fn norm_max(&self) -> f64 {
+                let mut result = 0.0;
+                self.entries(|_, _, value| result = max!(result, value.magnitude()));
+                result
+            }
+
+            /// Compute the trace.
+            ///
+            /// The trace of a rectangular matrix is the sum of its main
+            /// diagonal.
+            pub fn trace(&self) -> T
+            where
+                T: Number,
+            {
+                let mut result: T = Element::zero();
+                self.entries(|i, j, value| if i == j {
+                    result = result + value;
+                });
+                result
+            }
+
+            /// Compute the sums along an axis.
+            pub fn sum(&self, axis: Axis) -> Vec<T>
+            where
+                T: Number,
+            {
+                let mut result = vec![Element::zero(); length(self, axis)];
+                self.entries(|i, j, value| {
+                    let k = index(axis, i, j);
+                    result[k] = result[k] + value;
+                });
+                result
+            }
+
+            /// Compute the means along an axis.
+            pub fn mean(&self, axis: Axis) -> Vec<T>
+            where
+                T: Number + FromPrimitive,
+            {
+                let count = match axis {
+                    Axis::Row => self.columns(),
+                    Axis::Column => self.rows(),
+                };
+                let mut result = self.sum(axis);
+                if let Some(count) = T::from_usize(count) {
+                    if count != Element::zero() {
+                        for value in &mut result {
+                            *value = *value / count;
+                        }
+                    }
+                }
+                result
+            }
+
+            /// Compute the minima along an axis.
+            #[inline]
+            pub fn min(&self, axis: Axis) -> Vec<T>
+            where
+                T: PartialOrd,
+            {
+                extremum(self, axis, |left, right| left < right)
+            }
+
+            /// Compute the maxima along an axis.
+            #[inline]
+            pub fn max(&self, axis: Axis) -> Vec<T>
+            where
+                T: PartialOrd,
+            {
+                extremum(self, axis, |left, right| left > right)
+            }
+        }
+    );
+);
+
+reduction!(Banded);
+reduction!(Compressed);
+reduction!(Conventional);
+reduction!(Diagonal);
+reduction!(Packed);
+
+impl<T: Element> Entries<T> for Banded<T> {
+    #[inline]
+    fn entries<F>(&self, mut callback: F)
+    where
+        F: FnMut(usize, usize, T),
+    {
+        for (i, j, &value) in self.iter() {
+            callback(i, j, value);
+        }
+    }
+}
+
+impl<T: Element> Entries<T> for Compressed<T> {
+    #[inline]
+    fn entries<F>(&self, mut callback: F)
+    where
+        F: FnMut(usize, usize, T),
+    {
+        for (i, j, &value) in self.iter() {
+            callback(i, j, value);
+        }
+    }
+}
+
+impl<T: Element> Entries<T> for Conventional<T> {
+    fn entries<F>(&self, mut callback: F)
+    where
+        F: FnMut(usize, usize, T),
+    {
+        let rows = self.rows;
+        for (k, &value) in self.values.iter().enumerate() {
+            callback(k % rows, k / rows, value);
+        }
+    }
+}
+
+impl<T: Element> Entries<T> for Diagonal<T> {
+    #[inline]
+    fn entries<F>(&self, mut callback: F)
+    where
+        F: FnMut(usize, usize, T),
+    {
+        for (i, &value) in self.values.iter().enumerate() {
+            callback(i, i, value);
+        }
+    }
+}
+
+impl<T: Element> Entries<T> for Packed<T> {
+    // The off-diagonal elements of symmetric and Hermitian matrices are
+    // visited for both positions they represent.
+    fn entries<F>(&self, mut callback: F)
+    where
+        F: FnMut(usize, usize, T),
+    {
+        for (i, j, &value) in self.iter() {
+            match self.kind {
+                Kind::UnitTriangular if i == j => callback(i, j, T::one()),
+                Kind::Symmetric if i != j => {
+                    callback(i, j, value);
+                    callback(j, i, value);
+                }
+                Kind::Hermitian if i != j => {
+                    callback(i, j, value);
+                    callback(j, i, value.conj());
+                }
+                _ => callback(i, j, value),
+            }
+        }
+    }
+}
+
+fn extremum<M, T, F>(matrix: &M, axis: Axis, better: F) -> Vec<T>
+where
+    M: Entries<T>,
+    T: Element,
+    F: Fn(T, T) -> bool,
+{
+    let total = match axis {
+        Axis::Row => matrix.columns(),
+        Axis::Column => matrix.rows(),
+    };
+    let length = length(matrix, axis);
+    let mut result = vec![Element::zero(); length];
+    let mut counts = vec![0; length];
+    matrix.entries(|i, j, value| {
+        let k = index(axis, i, j);
+        if counts[k] == 0 || better(value, result[k]) {
+            result[k] = value;
+        }
+        counts[k] += 1;
+    });
+    let zero = T::zero();
+    for (value, &count) in result.iter_mut().zip(&counts) {
+        if count < total && better(zero, *value) {
+            *value = zero;
+        }
+    }
+    result
+}
+
+#[inline]
+fn index(axis: Axis, i: usize, j: usize) -> usize {
+    match axis {
+        Axis::Row => i,
+        Axis::Column => j,
+    }
+}
+
+#[inline]
+fn length<M: Size>(matrix: &M, axis: Axis) -> usize {
+    match axis {
+        Axis::Row => matrix.rows(),
+        Axis::Column => matrix.columns(),
+    }
+}
+
+fn norm<M, T>(matrix: &M, axis: Axis) -> f64
+where
+    M: Entries<T>,
+    T: Element,
+{
+    let mut sums = vec![0.0; length(matrix, axis)];
+    matrix.entries(|i, j, value| sums[index(axis, i, j)] += value.magnitude());
+    sums.into_iter().fold(0.0, |result, sum| max!(result, sum))
+}
+
+#[cfg(test)]
+mod tests {
+    use assert;
+
+    use format::compressed::Variant;
+    use format::packed::{self, Kind};
+    use format::Axis;
+    use prelude::*;
+
+    #[test]
+    fn extremum() {
+        let mut matrix = Compressed::new((2, 3), Variant::Column);
+        matrix.set((0, 0), 3.0);
+        matrix.set((0, 1), 1.0);
+        matrix.set((0, 2), 2.0);
+        matrix.set((1, 1), -4.0);
+        assert_eq!(matrix.min(Axis::Row), vec![1.0, -4.0]);
+        assert_eq!(matrix.max(Axis::Row), vec![3.0, 0.0]);
+        assert_eq!(matrix.min(Axis::Column), vec![0.0, -4.0, 0.0]);
+        assert_eq!(matrix.max(Axis::Column), vec![3.0, 1.0, 2.0]);
+    }
+
+    #[test]
+    fn norm() {
+        let matrix = Conventional::from_vec(
+            2,
+            matrix![
+                1.0, -2.0;
+                3.0,  4.0;
+            ],
+        );
+        assert::close(matrix.norm_frobenius(), 30f64.sqrt(), 1e-14);
+        assert_eq!(matrix.norm_one(), 6.0);
+        assert_eq!(matrix.norm_infinity(), 7.0);
+        assert_eq!(matrix.norm_max(), 4.0);
+
+        let matrix = Packed::from_parts(
+            3,
+            packed::Variant::Lower,
+            Kind::Symmetric,
+            vec![1.0, -2.0, 0.0, 3.0, 5.0, 6.0],
+        )
+        .unwrap();
+        let conventional = Conventional::from(matrix.clone());
+        assert_eq!(matrix.norm_frobenius(), conventional.norm_frobenius());
+        assert_eq!(matrix.norm_one(), 11.0);
+        assert_eq!(matrix.norm_infinity(), 11.0);
+
+        let matrix = Banded::from(Diagonal::from_vec((3, 2), vec![-1.0, 2.0]));
+        assert_eq!(matrix.norm_one(), 2.0);
+        assert_eq!(matrix.norm_max(), 2.0);
+    }
+
+    #[test]
+    fn sum() {
+        let matrix = Packed::from_parts(
+            2,
+            packed::Variant::Upper,
+            Kind::UnitTriangular,
+            vec![0.0, 3.0, 0.0],
+        )
+        .unwrap();
+        assert_eq!(matrix.sum(Axis::Row), vec![4.0, 1.0]);
+        assert_eq!(matrix.sum(Axis::Column), vec![1.0, 4.0]);
+        assert_eq!(matrix.mean(Axis::Row), vec![2.0, 0.5]);
+        assert_eq!(matrix.trace(), 2.0);
+
+        let matrix = Diagonal::from_vec((2, 4), vec![4, 8]);
+        assert_eq!(matrix.mean(Axis::Row), vec![1, 2]);
+        assert_eq!(matrix.trace(), 12);
+    }
+}
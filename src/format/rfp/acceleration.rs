@@ -0,0 +1,132 @@
+use lapack as backend;
+
+use std::any::TypeId;
+use std::slice;
+
+use format::packed::Variant;
+use format::Rfp;
+use {Element, Result};
+
+pub fn factorize<T>(matrix: &mut Rfp<T>) -> Option<Result<()>>
+where
+    T: 'static + Element,
+{
+    if TypeId::of::<T>() != TypeId::of::<f64>() {
+        return None;
+    }
+    let uplo = uplo(matrix.variant);
+    let values = &mut matrix.values;
+    let values =
+        unsafe { slice::from_raw_parts_mut(values.as_mut_ptr() as *mut f64, values.len()) };
+    Some(cholesky(values, uplo, matrix.size))
+}
+
+pub fn solve<T>(matrix: &Rfp<T>, right: &mut [T], transpose: bool, unit: bool) -> bool
+where
+    T: 'static + Element,
+{
+    if TypeId::of::<T>() != TypeId::of::<f64>() {
+        return false;
+    }
+    let (values, right) = unsafe { cast(&matrix.values, right) };
+    let trans = if transpose { b'T' } else { b'N' };
+    let diag = if unit { b'U' } else { b'N' };
+    triangular_solve(
+        values,
+        right,
+        uplo(matrix.variant),
+        trans,
+        diag,
+        matrix.size,
+    );
+    true
+}
+
+pub fn solve_cholesky<T>(factors: &Rfp<T>, right: &mut [T]) -> bool
+where
+    T: 'static + Element,
+{
+    if TypeId::of::<T>() != TypeId::of::<f64>() {
+        return false;
+    }
+    let (values, right) = unsafe { cast(&factors.values, right) };
+    cholesky_solve(values, right, uplo(factors.variant), factors.size);
+    true
+}
+
+unsafe fn cast<'l, T>(values: &'l [T], right: &'l mut [T]) -> (&'l [f64], &'l mut [f64]) {
+    (
+        slice::from_raw_parts(values.as_ptr() as *const f64, values.len()),
+        slice::from_raw_parts_mut(right.as_mut_ptr() as *mut f64, right.len()),
+    )
+}
+
+fn cholesky(values: &mut [f64], uplo: u8, n: usize) -> Result<()> {
+    debug_assert_eq!(values.len(), n * (n + 1) / 2);
+    let mut info = 0;
+    unsafe {
+        backend::dpftrf(b'N', uplo, n as i32, values, &mut info);
+    }
+    if info < 0 {
+        raise!("encountered invalid arguments");
+    } else if info > 0 {
+        raise!(format!(
+            "the matrix is not positive definite (column {})",
+            info - 1
+        ));
+    }
+    Ok(())
+}
+
+fn cholesky_solve(values: &[f64], right: &mut [f64], uplo: u8, n: usize) {
+    debug_assert_eq!(values.len(), n * (n + 1) / 2);
+    if n == 0 {
+        return;
+    }
+    debug_assert_eq!(right.len() % n, 0);
+    let mut info = 0;
+    unsafe {
+        backend::dpftrs(
+            b'N',
+            uplo,
+            n as i32,
+            (right.len() / n) as i32,
+            values,
+            right,
+            n as i32,
+            &mut info,
+        );
+    }
+    debug_assert_eq!(info, 0);
+}
+
+fn triangular_solve(values: &[f64], right: &mut [f64], uplo: u8, trans: u8, diag: u8, n: usize) {
+    debug_assert_eq!(values.len(), n * (n + 1) / 2);
+    if n == 0 {
+        return;
+    }
+    debug_assert_eq!(right.len() % n, 0);
+    unsafe {
+        backend::dtfsm(
+            b'N',
+            b'L',
+            uplo,
+            trans,
+            diag,
+            n as i32,
+            (right.len() / n) as i32,
+            1.0,
+            values,
+            right,
+            n as i32,
+        );
+    }
+}
+
+#[inline]
+fn uplo(variant: Variant) -> u8 {
+    match variant {
+        Variant::Lower => b'L',
+        Variant::Upper => b'U',
+    }
+}
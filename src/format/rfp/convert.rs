@@ -0,0 +1,135 @@
+use format::packed::{Kind, Variant};
+use format::{Conventional, Packed, Rfp};
+use {Element, Number, Result};
+
+impl<T> Rfp<T>
+where
+    T: Element + Number,
+{
+    /// Create a matrix of a specific kind from the lower triangle when
+    /// `variant = Lower` or upper triangle when `variant = Upper` of a
+    /// conventional matrix.
+    ///
+    /// If `tolerance` is given, the function fails unless the matrix is of the
+    /// given kind up to the tolerance.
+    #[inline]
+    pub fn from_conventional(
+        matrix: &Conventional<T>,
+        variant: Variant,
+        kind: Kind,
+        tolerance: Option<f64>,
+    ) -> Result<Self> {
+        Ok(Packed::from_conventional(matrix, variant, kind, tolerance)?.into())
+    }
+}
+
+impl<'l, T: Element> From<&'l Packed<T>> for Rfp<T> {
+    fn from(matrix: &'l Packed<T>) -> Self {
+        let &Packed {
+            size,
+            variant,
+            kind,
+            ..
+        } = validate!(matrix);
+        let mut result = Rfp::new(size, variant, kind);
+        for (i, j, &value) in matrix.iter() {
+            let k = result.position(i, j).unwrap();
+            result.values[k] = value;
+        }
+        result
+    }
+}
+
+impl<T: Element> From<Packed<T>> for Rfp<T> {
+    #[inline]
+    fn from(matrix: Packed<T>) -> Self {
+        (&matrix).into()
+    }
+}
+
+impl<'l, T: Element> From<&'l Rfp<T>> for Packed<T> {
+    fn from(matrix: &'l Rfp<T>) -> Self {
+        let &Rfp {
+            size,
+            variant,
+            kind,
+            ref values,
+        } = validate!(matrix);
+        let mut result = Packed::new(size, variant);
+        result.kind = kind;
+        for (i, j, value) in result.iter_mut() {
+            *value = values[matrix.position(i, j).unwrap()];
+        }
+        result
+    }
+}
+
+impl<T: Element> From<Rfp<T>> for Packed<T> {
+    #[inline]
+    fn from(matrix: Rfp<T>) -> Self {
+        (&matrix).into()
+    }
+}
+
+impl<'l, T: Element> From<&'l Rfp<T>> for Conventional<T> {
+    /// Convert into a conventional matrix.
+    ///
+    /// The triangle that is not stored is filled in according to the kind of
+    /// the matrix.
+    #[inline]
+    fn from(matrix: &'l Rfp<T>) -> Self {
+        Packed::from(matrix).into()
+    }
+}
+
+impl<T: Element> From<Rfp<T>> for Conventional<T> {
+    #[inline]
+    fn from(matrix: Rfp<T>) -> Self {
+        (&matrix).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use format::packed::{Kind, Variant};
+    use prelude::*;
+
+    #[test]
+    fn from_conventional() {
+        let matrix = Conventional::from_vec(
+            4,
+            matrix![
+                1.0, 2.0, 3.0,  4.0;
+                0.0, 5.0, 6.0,  7.0;
+                0.0, 0.0, 8.0,  9.0;
+                0.0, 0.0, 0.0, 10.0;
+            ],
+        );
+        let rfp =
+            Rfp::from_conventional(&matrix, Variant::Upper, Kind::Triangular, Some(0.0)).unwrap();
+        assert_eq!(
+            &rfp.values,
+            &[3.0, 6.0, 8.0, 1.0, 2.0, 4.0, 7.0, 9.0, 10.0, 5.0]
+        );
+        assert_eq!(Conventional::from(&rfp), matrix);
+        assert!(
+            Rfp::from_conventional(&matrix, Variant::Upper, Kind::Symmetric, Some(0.0)).is_err()
+        );
+    }
+
+    #[test]
+    fn packed() {
+        for size in 0..7 {
+            for &variant in &[Variant::Lower, Variant::Upper] {
+                let mut matrix = Packed::new(size, variant);
+                matrix.kind = Kind::Symmetric;
+                for (k, value) in matrix.values.iter_mut().enumerate() {
+                    *value = (k + 1) as f64;
+                }
+                let rfp = Rfp::from(&matrix);
+                assert_eq!(Conventional::from(&rfp), Conventional::from(&matrix));
+                assert_eq!(Packed::from(rfp), matrix);
+            }
+        }
+    }
+}
@@ -0,0 +1,297 @@
+use num_traits::Float;
+
+use decomposition::{Condition, Solve};
+use format::packed::{Kind, Variant};
+#[cfg(feature = "acceleration")]
+use format::rfp::acceleration;
+use format::rfp::{operation, Block, Layout};
+use format::Rfp;
+use {Element, Result};
+
+/// The Cholesky decomposition of a symmetric positive-definite matrix in the
+/// rectangular full packed format.
+///
+/// The decomposition is computed by blocks as done by [`pftrf`][1] in LAPACK,
+/// and systems are solved as done by [`pftrs`][2]. With the `acceleration`
+/// feature, both are delegated to LAPACK for `f64`.
+///
+/// [1]: http://www.netlib.org/lapack/explore-html/d1/d16/dpftrf_8f.html
+/// [2]: http://www.netlib.org/lapack/explore-html/d8/d4c/dpftrs_8f.html
+#[derive(Clone, Debug, PartialEq)]
+pub struct Cholesky<T: Element> {
+    /// The triangular factor stored in place of the triangle of the original
+    /// matrix. When `variant = Upper`, the factor is `U` such that `A = Uᵀ ×
+    /// U`. When `variant = Lower`, the factor is `L` such that `A = L × Lᵀ`.
+    pub factors: Rfp<T>,
+}
+
+impl<T> Rfp<T>
+where
+    T: 'static + Element + Float,
+{
+    /// Perform the Cholesky decomposition.
+    ///
+    /// The matrix should be symmetric. The function fails when the matrix is
+    /// not positive definite.
+    pub fn cholesky(&self) -> Result<Cholesky<T>> {
+        if let Kind::Triangular | Kind::UnitTriangular = self.kind {
+            raise!("the Cholesky decomposition requires a symmetric matrix");
+        }
+        let mut factors = validate!(self).clone();
+        factors.kind = Kind::Triangular;
+        #[cfg(feature = "acceleration")]
+        {
+            if let Some(result) = acceleration::factorize(&mut factors) {
+                result?;
+                return Ok(Cholesky { factors });
+            }
+        }
+        factorize(&mut factors)?;
+        Ok(Cholesky { factors })
+    }
+}
+
+impl<T> Cholesky<T>
+where
+    T: Element + Float,
+{
+    /// Compute the natural logarithm of the determinant.
+    pub fn log_determinant(&self) -> T {
+        let factors = &self.factors;
+        let mut sum: T = Element::zero();
+        for j in 0..factors.size {
+            sum = sum + factors.values[factors.position(j, j).unwrap()].ln();
+        }
+        sum + sum
+    }
+}
+
+impl<T> Condition<T> for Cholesky<T>
+where
+    T: 'static + Element + Float,
+{
+    #[inline]
+    fn order(&self) -> usize {
+        self.factors.size
+    }
+}
+
+impl<T> Solve<T> for Cholesky<T>
+where
+    T: 'static + Element + Float,
+{
+    fn solve(&self, right: &mut [T]) {
+        let factors = &self.factors;
+        #[cfg(feature = "acceleration")]
+        {
+            if acceleration::solve_cholesky(factors, right) {
+                return;
+            }
+        }
+        let lower = factors.variant == Variant::Lower;
+        operation::solve(factors, right, !lower, false);
+        operation::solve(factors, right, lower, false);
+    }
+
+    #[inline]
+    fn solve_transpose(&self, right: &mut [T]) {
+        self.solve(right)
+    }
+}
+
+fn factorize<T>(matrix: &mut Rfp<T>) -> Result<()>
+where
+    T: Element + Float,
+{
+    let Layout {
+        first,
+        second,
+        offdiagonal,
+    } = matrix.layout();
+    let lower = matrix.variant == Variant::Lower;
+    let a = &mut matrix.values;
+    factorize_lower(a, first, 0)?;
+    if lower {
+        for j in 0..offdiagonal.columns {
+            let pivot = a[first.at(j, j)];
+            for i in 0..offdiagonal.rows {
+                let mut value = a[offdiagonal.at(i, j)];
+                for k in 0..j {
+                    value = value - a[offdiagonal.at(i, k)] * a[first.at(j, k)];
+                }
+                a[offdiagonal.at(i, j)] = value / pivot;
+            }
+        }
+    } else {
+        for j in 0..offdiagonal.columns {
+            for i in 0..offdiagonal.rows {
+                let mut value = a[offdiagonal.at(i, j)];
+                for k in 0..i {
+                    value = value - a[first.at(i, k)] * a[offdiagonal.at(k, j)];
+                }
+                a[offdiagonal.at(i, j)] = value / a[first.at(i, i)];
+            }
+        }
+    }
+    for j in 0..second.columns {
+        for i in 0..(j + 1) {
+            let mut value = a[second.at(i, j)];
+            if lower {
+                for k in 0..offdiagonal.columns {
+                    value = value - a[offdiagonal.at(i, k)] * a[offdiagonal.at(j, k)];
+                }
+            } else {
+                for k in 0..offdiagonal.rows {
+                    value = value - a[offdiagonal.at(k, i)] * a[offdiagonal.at(k, j)];
+                }
+            }
+            a[second.at(i, j)] = value;
+        }
+    }
+    factorize_upper(a, second, first.rows)
+}
+
+fn factorize_lower<T>(a: &mut [T], block: Block, start: usize) -> Result<()>
+where
+    T: Element + Float,
+{
+    for j in 0..block.columns {
+        let mut value = a[block.at(j, j)];
+        for k in 0..j {
+            value = value - a[block.at(j, k)] * a[block.at(j, k)];
+        }
+        if value <= Element::zero() {
+            raise!(format!(
+                "the matrix is not positive definite (column {})",
+                start + j
+            ));
+        }
+        let pivot = value.sqrt();
+        a[block.at(j, j)] = pivot;
+        for i in (j + 1)..block.rows {
+            let mut value = a[block.at(i, j)];
+            for k in 0..j {
+                value = value - a[block.at(i, k)] * a[block.at(j, k)];
+            }
+            a[block.at(i, j)] = value / pivot;
+        }
+    }
+    Ok(())
+}
+
+fn factorize_upper<T>(a: &mut [T], block: Block, start: usize) -> Result<()>
+where
+    T: Element + Float,
+{
+    for j in 0..block.columns {
+        for i in 0..j {
+            let mut value = a[block.at(i, j)];
+            for k in 0..i {
+                value = value - a[block.at(k, i)] * a[block.at(k, j)];
+            }
+            a[block.at(i, j)] = value / a[block.at(i, i)];
+        }
+        let mut value = a[block.at(j, j)];
+        for k in 0..j {
+            value = value - a[block.at(k, j)] * a[block.at(k, j)];
+        }
+        if value <= Element::zero() {
+            raise!(format!(
+                "the matrix is not positive definite (column {})",
+                start + j
+            ));
+        }
+        a[block.at(j, j)] = value.sqrt();
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use assert;
+    use format::packed::{Kind, Variant};
+    use prelude::*;
+
+    #[test]
+    fn cholesky() {
+        for size in 0..8 {
+            for &variant in &[Variant::Lower, Variant::Upper] {
+                let mut matrix = Packed::new(size, variant);
+                matrix.kind = Kind::Symmetric;
+                for (i, j, value) in matrix.iter_mut() {
+                    *value = if i == j {
+                        size as f64
+                    } else {
+                        1.0 / (1 + i + j) as f64
+                    };
+                }
+                let cholesky = Rfp::from(&matrix).cholesky().unwrap();
+                let factors = Conventional::from(&cholesky.factors);
+                let expected = Conventional::from(&matrix);
+                for i in 0..size {
+                    for j in 0..size {
+                        let mut sum = 0.0;
+                        for k in 0..size {
+                            sum += match variant {
+                                Variant::Lower => factors[(i, k)] * factors[(j, k)],
+                                Variant::Upper => factors[(k, i)] * factors[(k, j)],
+                            };
+                        }
+                        assert::close(sum, expected[(i, j)], 1e-12);
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn cholesky_invalid() {
+        let mut matrix = Rfp::<f64>::new(3, Variant::Upper, Kind::Symmetric);
+        assert!(matrix.cholesky().is_err());
+        matrix.kind = Kind::Triangular;
+        assert!(matrix.cholesky().is_err());
+    }
+
+    #[test]
+    fn log_determinant() {
+        let matrix = Packed::from_conventional(
+            &Conventional::from_vec(
+                3,
+                matrix![
+                    4.0, 2.0, 0.0;
+                    2.0, 5.0, 1.0;
+                    0.0, 1.0, 2.0;
+                ],
+            ),
+            Variant::Lower,
+            Kind::Symmetric,
+            Some(0.0),
+        )
+        .unwrap();
+        let cholesky = Rfp::from(matrix).cholesky().unwrap();
+        assert::close(cholesky.log_determinant(), 28f64.ln(), 1e-12);
+    }
+
+    #[test]
+    fn solve() {
+        for size in 1..8 {
+            for &variant in &[Variant::Lower, Variant::Upper] {
+                let mut matrix = Packed::new(size, variant);
+                matrix.kind = Kind::Symmetric;
+                for (i, j, value) in matrix.iter_mut() {
+                    *value = if i == j {
+                        size as f64
+                    } else {
+                        1.0 / (1 + i + j) as f64
+                    };
+                }
+                let cholesky = Rfp::from(&matrix).cholesky().unwrap();
+                let expected = (0..(2 * size)).map(|i| i as f64 - 3.0).collect::<Vec<_>>();
+                let mut right = vec![0.0; 2 * size];
+                matrix.multiply_into(&expected, &mut right);
+                cholesky.solve(&mut right);
+                assert::close(&right, &expected, 1e-12);
+            }
+        }
+    }
+}
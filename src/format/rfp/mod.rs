@@ -0,0 +1,214 @@
+//! The rectangular full packed format.
+//!
+//! The format is suitable for symmetric, Hermitian, and triangular matrices.
+//! Similarly to the packed format, only one triangle is stored, which takes
+//! `size × (size + 1) / 2` elements. However, the triangle is arranged as a
+//! full rectangle, which makes it possible to process it by blocks. The format
+//! is compatible with the [one][1] adopted by [LAPACK][2] with `transr = N`.
+//!
+//! [1]: http://www.netlib.org/lapack/lawnspdf/lawn199.pdf
+//! [2]: http://www.netlib.org/lapack
+
+use format::packed::{Kind, Variant};
+use {Element, Matrix, Position, Size};
+
+/// A rectangular full packed matrix.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Rfp<T: Element> {
+    /// The number of rows or columns.
+    pub size: usize,
+    /// The format variant.
+    pub variant: Variant,
+    /// The matrix kind.
+    pub kind: Kind,
+    /// The values of the lower triangle when `variant = Lower` or upper
+    /// triangle when `variant = Upper` arranged as a `(size + e) × ⌈size / 2⌉`
+    /// matrix stored in the column-major order, where `e` is one when `size`
+    /// is even and zero otherwise.
+    pub values: Vec<T>,
+}
+
+macro_rules! new(
+    ($size:expr, $variant:expr, $kind:expr, $values:expr) => (
+        Rfp { size: $size, variant: $variant, kind: $kind, values: $values }
+    );
+);
+
+#[cfg(feature = "acceleration")]
+mod acceleration;
+mod convert;
+mod decomposition;
+mod operation;
+
+pub use self::decomposition::Cholesky;
+
+// The stored triangle is partitioned into two diagonal blocks and one
+// off-diagonal block. The first diagonal block is always stored as a lower
+// triangle and the second one as an upper triangle, which corresponds to the
+// original block when `variant = Lower` and its transpose otherwise.
+#[derive(Clone, Copy, Debug)]
+struct Layout {
+    first: Block,
+    second: Block,
+    offdiagonal: Block,
+}
+
+#[derive(Clone, Copy, Debug)]
+struct Block {
+    offset: usize,
+    leading: usize,
+    rows: usize,
+    columns: usize,
+}
+
+#[cfg(debug_assertions)]
+impl<T: Element> ::format::Validate for Rfp<T> {
+    fn validate(&self) {
+        assert_eq!(self.values.len(), self.size * (self.size + 1) / 2);
+    }
+}
+
+size!(Rfp, size, size);
+
+impl<T: Element> Rfp<T> {
+    /// Create a zero matrix.
+    pub fn new<S: Size>(size: S, variant: Variant, kind: Kind) -> Self {
+        let (rows, _columns) = size.dimensions();
+        debug_assert!(rows == _columns);
+        new!(rows, variant, kind, vec![T::zero(); rows * (rows + 1) / 2])
+    }
+
+    /// Read an element.
+    ///
+    /// The element is interpreted according to the kind of the matrix.
+    pub fn get<P: Position>(&self, position: P) -> T {
+        let (i, j) = position.coordinates();
+        debug_assert!(i < self.size && j < self.size);
+        match (self.kind, self.position(i, j), self.position(j, i)) {
+            (Kind::UnitTriangular, _, _) if i == j => T::one(),
+            (_, Some(k), _) => self.values[k],
+            (Kind::Symmetric, _, Some(k)) => self.values[k],
+            (Kind::Hermitian, _, Some(k)) => self.values[k].conj(),
+            _ => T::zero(),
+        }
+    }
+
+    fn layout(&self) -> Layout {
+        let n = self.size;
+        let even = match n % 2 {
+            0 => 1,
+            _ => 0,
+        };
+        let leading = n + even;
+        let block = |offset, rows, columns| Block {
+            offset,
+            leading,
+            rows,
+            columns,
+        };
+        match self.variant {
+            Variant::Lower => {
+                let (first, second) = (n - n / 2, n / 2);
+                Layout {
+                    first: block(even, first, first),
+                    second: block((1 - even) * leading, second, second),
+                    offdiagonal: block(first + even, second, first),
+                }
+            }
+            Variant::Upper => {
+                let (first, second) = (n / 2, n - n / 2);
+                Layout {
+                    first: block(second + even, first, first),
+                    second: block(first, second, second),
+                    offdiagonal: block(0, first, second),
+                }
+            }
+        }
+    }
+
+    fn position(&self, i: usize, j: usize) -> Option<usize> {
+        let Block {
+            rows: first,
+            leading,
+            ..
+        } = self.layout().first;
+        let even = leading - self.size;
+        match self.variant {
+            Variant::Lower if i >= j => Some(if j < first {
+                j * leading + even + i
+            } else {
+                (1 - even + i - first) * leading + j - first
+            }),
+            Variant::Upper if i <= j => Some(if j < first {
+                i * leading + self.size - first + even + j
+            } else {
+                (j - first) * leading + i
+            }),
+            _ => None,
+        }
+    }
+}
+
+impl Block {
+    #[inline(always)]
+    fn at(&self, i: usize, j: usize) -> usize {
+        self.offset + j * self.leading + i
+    }
+}
+
+impl<T: Element> Matrix for Rfp<T> {
+    type Element = T;
+
+    fn nonzeros(&self) -> usize {
+        ::format::Packed::from(self).nonzeros()
+    }
+
+    #[inline]
+    fn zero<S: Size>(size: S) -> Self {
+        Rfp::new(size, Variant::Lower, Kind::Triangular)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use format::packed::{Kind, Variant};
+    use prelude::*;
+
+    #[test]
+    fn position() {
+        for size in 0..8 {
+            for &variant in &[Variant::Lower, Variant::Upper] {
+                let matrix = Rfp::<f64>::new(size, variant, Kind::Triangular);
+                let mut positions = vec![];
+                for j in 0..size {
+                    for i in 0..size {
+                        if let Some(k) = matrix.position(i, j) {
+                            positions.push(k);
+                        }
+                    }
+                }
+                positions.sort();
+                assert_eq!(positions, (0..(size * (size + 1) / 2)).collect::<Vec<_>>());
+            }
+        }
+    }
+
+    #[test]
+    fn get() {
+        let matrix = new!(
+            3,
+            Variant::Lower,
+            Kind::Symmetric,
+            vec![1.0, 2.0, 3.0, 6.0, 4.0, 5.0]
+        );
+        assert_eq!(
+            &*Conventional::from(&matrix),
+            &*matrix![
+                1.0, 2.0, 3.0;
+                2.0, 4.0, 5.0;
+                3.0, 5.0, 6.0;
+            ]
+        );
+        assert_eq!(matrix.get((0, 2)), 3.0);
+    }
+}
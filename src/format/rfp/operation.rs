@@ -0,0 +1,215 @@
+use format::packed::{Kind, Variant};
+#[cfg(feature = "acceleration")]
+use format::rfp::acceleration;
+use format::rfp::{Block, Layout};
+use format::Rfp;
+use {Element, Number, Result};
+
+impl<T> Rfp<T>
+where
+    T: 'static + Element + Number,
+{
+    /// Solve `A × X = B` overwriting `B` with `X`.
+    ///
+    /// The right-hand sides are stored in the column-major order. The
+    /// function fails when the matrix is neither triangular nor unit
+    /// triangular. With the `acceleration` feature, the solution is delegated
+    /// to [`tfsm`][1] in LAPACK for `f64`.
+    ///
+    /// [1]: http://www.netlib.org/lapack/explore-html/d7/d00/dtfsm_8f.html
+    pub fn solve(&self, right: &mut [T]) -> Result<()> {
+        let unit = is_unit(self.kind)?;
+        solve(self, right, false, unit);
+        Ok(())
+    }
+
+    /// Solve `Aᵀ × X = B` overwriting `B` with `X`.
+    ///
+    /// The right-hand sides are stored in the column-major order. The
+    /// function fails when the matrix is neither triangular nor unit
+    /// triangular.
+    pub fn solve_transpose(&self, right: &mut [T]) -> Result<()> {
+        let unit = is_unit(self.kind)?;
+        solve(self, right, true, unit);
+        Ok(())
+    }
+}
+
+fn is_unit(kind: Kind) -> Result<bool> {
+    match kind {
+        Kind::Triangular => Ok(false),
+        Kind::UnitTriangular => Ok(true),
+        _ => {
+            raise!("the solution requires a triangular matrix");
+        }
+    }
+}
+
+pub(super) fn solve<T>(matrix: &Rfp<T>, right: &mut [T], transpose: bool, unit: bool)
+where
+    T: 'static + Element + Number,
+{
+    let n = matrix.size;
+    if n == 0 {
+        return;
+    }
+    debug_assert_eq!(right.len() % n, 0);
+    #[cfg(feature = "acceleration")]
+    {
+        if acceleration::solve(matrix, right, transpose, unit) {
+            return;
+        }
+    }
+    let Layout {
+        first,
+        second,
+        offdiagonal,
+    } = matrix.layout();
+    let a = &matrix.values;
+    for b in right.chunks_mut(n) {
+        let (b1, b2) = b.split_at_mut(first.rows);
+        match (matrix.variant, transpose) {
+            (Variant::Lower, false) => {
+                solve_lower(a, first, false, unit, b1);
+                subtract_product(a, offdiagonal, false, b1, b2);
+                solve_upper(a, second, true, unit, b2);
+            }
+            (Variant::Lower, true) => {
+                solve_upper(a, second, false, unit, b2);
+                subtract_product(a, offdiagonal, true, b2, b1);
+                solve_lower(a, first, true, unit, b1);
+            }
+            (Variant::Upper, false) => {
+                solve_upper(a, second, false, unit, b2);
+                subtract_product(a, offdiagonal, false, b2, b1);
+                solve_lower(a, first, true, unit, b1);
+            }
+            (Variant::Upper, true) => {
+                solve_lower(a, first, false, unit, b1);
+                subtract_product(a, offdiagonal, true, b1, b2);
+                solve_upper(a, second, true, unit, b2);
+            }
+        }
+    }
+}
+
+fn solve_lower<T>(a: &[T], block: Block, transpose: bool, unit: bool, b: &mut [T])
+where
+    T: Element + Number,
+{
+    let n = block.rows;
+    if transpose {
+        for j in (0..n).rev() {
+            let mut value = b[j];
+            for i in (j + 1)..n {
+                value = value - a[block.at(i, j)] * b[i];
+            }
+            b[j] = if unit {
+                value
+            } else {
+                value / a[block.at(j, j)]
+            };
+        }
+    } else {
+        for j in 0..n {
+            if !unit {
+                b[j] = b[j] / a[block.at(j, j)];
+            }
+            let value = b[j];
+            for i in (j + 1)..n {
+                b[i] = b[i] - a[block.at(i, j)] * value;
+            }
+        }
+    }
+}
+
+fn solve_upper<T>(a: &[T], block: Block, transpose: bool, unit: bool, b: &mut [T])
+where
+    T: Element + Number,
+{
+    let n = block.rows;
+    if transpose {
+        for j in 0..n {
+            let mut value = b[j];
+            for i in 0..j {
+                value = value - a[block.at(i, j)] * b[i];
+            }
+            b[j] = if unit {
+                value
+            } else {
+                value / a[block.at(j, j)]
+            };
+        }
+    } else {
+        for j in (0..n).rev() {
+            if !unit {
+                b[j] = b[j] / a[block.at(j, j)];
+            }
+            let value = b[j];
+            for i in 0..j {
+                b[i] = b[i] - a[block.at(i, j)] * value;
+            }
+        }
+    }
+}
+
+fn subtract_product<T>(a: &[T], block: Block, transpose: bool, x: &[T], y: &mut [T])
+where
+    T: Element + Number,
+{
+    for j in 0..block.columns {
+        if transpose {
+            let mut value = y[j];
+            for i in 0..block.rows {
+                value = value - a[block.at(i, j)] * x[i];
+            }
+            y[j] = value;
+        } else {
+            let value = x[j];
+            for i in 0..block.rows {
+                y[i] = y[i] - a[block.at(i, j)] * value;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use assert;
+    use format::packed::{Kind, Variant};
+    use prelude::*;
+
+    #[test]
+    fn solve() {
+        for size in 1..8 {
+            for &variant in &[Variant::Lower, Variant::Upper] {
+                for &kind in &[Kind::Triangular, Kind::UnitTriangular] {
+                    let mut packed = Packed::new(size, variant);
+                    packed.kind = kind;
+                    for (i, j, value) in packed.iter_mut() {
+                        *value = if i == j {
+                            2.0 + i as f64
+                        } else {
+                            1.0 / (1 + i + j) as f64
+                        };
+                    }
+                    let matrix = Rfp::from(&packed);
+                    let expected = (0..(2 * size)).map(|i| i as f64 - 3.0).collect::<Vec<_>>();
+
+                    let mut right = vec![0.0; 2 * size];
+                    packed.multiply_into(&expected, &mut right);
+                    matrix.solve(&mut right).unwrap();
+                    assert::close(&right, &expected, 1e-12);
+
+                    let mut right = vec![0.0; 2 * size];
+                    packed.transpose().multiply_into(&expected, &mut right);
+                    matrix.solve_transpose(&mut right).unwrap();
+                    assert::close(&right, &expected, 1e-12);
+                }
+            }
+        }
+        let matrix = Rfp::new(2, Variant::Lower, Kind::Symmetric);
+        assert!(matrix.solve(&mut [1.0, 1.0]).is_err());
+        assert!(matrix.solve_transpose(&mut [1.0, 1.0]).is_err());
+    }
+}
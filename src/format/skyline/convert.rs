@@ -0,0 +1,140 @@
+use format::{Compressed, Conventional, Skyline};
+use Element;
+
+impl<'l, T: Element> From<&'l Compressed<T>> for Skyline<T> {
+    /// Convert from a compressed matrix.
+    ///
+    /// The matrix is assumed to be symmetric, and only its upper triangle is
+    /// taken into account.
+    fn from(matrix: &'l Compressed<T>) -> Self {
+        debug_assert_eq!(matrix.rows, matrix.columns);
+        let upper = matrix
+            .iter()
+            .filter(|&(i, j, _)| i <= j)
+            .map(|(i, j, &value)| (i, j, value));
+        from_triplets(matrix.columns, upper)
+    }
+}
+
+impl<T: Element> From<Compressed<T>> for Skyline<T> {
+    #[inline]
+    fn from(matrix: Compressed<T>) -> Self {
+        (&matrix).into()
+    }
+}
+
+impl<'l, T: Element> From<&'l Conventional<T>> for Skyline<T> {
+    /// Convert from a conventional matrix.
+    ///
+    /// The matrix is assumed to be symmetric, and only its upper triangle is
+    /// taken into account.
+    fn from(matrix: &'l Conventional<T>) -> Self {
+        debug_assert_eq!(matrix.rows, matrix.columns);
+        let size = matrix.rows;
+        let upper = (0..size)
+            .flat_map(|j| (0..(j + 1)).map(move |i| (i, j)))
+            .map(|(i, j)| (i, j, matrix.values[j * size + i]))
+            .filter(|&(_, _, value)| !value.is_zero());
+        from_triplets(size, upper)
+    }
+}
+
+impl<T: Element> From<Conventional<T>> for Skyline<T> {
+    #[inline]
+    fn from(matrix: Conventional<T>) -> Self {
+        (&matrix).into()
+    }
+}
+
+impl<'l, T: Element> From<&'l Skyline<T>> for Conventional<T> {
+    fn from(matrix: &'l Skyline<T>) -> Self {
+        let &Skyline {
+            size,
+            ref values,
+            ref offsets,
+        } = validate!(matrix);
+        let mut result = Conventional::new(size);
+        for j in 0..size {
+            let first = first!(offsets, j);
+            for (i, &value) in (first..(j + 1)).zip(&values[offsets[j]..offsets[j + 1]]) {
+                result.values[j * size + i] = value;
+                result.values[i * size + j] = value;
+            }
+        }
+        result
+    }
+}
+
+impl<T: Element> From<Skyline<T>> for Conventional<T> {
+    #[inline]
+    fn from(matrix: Skyline<T>) -> Self {
+        (&matrix).into()
+    }
+}
+
+fn from_triplets<T, I>(size: usize, triplets: I) -> Skyline<T>
+where
+    T: Element,
+    I: Iterator<Item = (usize, usize, T)>,
+{
+    let triplets = triplets.collect::<Vec<_>>();
+    let mut heights = vec![0; size];
+    for &(i, j, _) in &triplets {
+        heights[j] = max!(heights[j], j - i);
+    }
+    let mut matrix = Skyline::with_heights(&heights);
+    for (i, j, value) in triplets {
+        let k = matrix.offsets[j + 1] - 1 - (j - i);
+        matrix.values[k] = value;
+    }
+    matrix
+}
+
+#[cfg(test)]
+mod tests {
+    use prelude::*;
+
+    #[test]
+    fn from_compressed() {
+        let matrix = Skyline::from(Compressed::from(Conventional::from_vec(
+            4,
+            matrix![
+                1.0, 2.0, 0.0, 5.0;
+                2.0, 3.0, 0.0, 0.0;
+                0.0, 0.0, 4.0, 7.0;
+                5.0, 0.0, 7.0, 8.0;
+            ],
+        )));
+        assert_eq!(matrix.offsets, vec![0, 1, 3, 4, 8]);
+        assert_eq!(matrix.values, vec![1.0, 2.0, 3.0, 4.0, 5.0, 0.0, 7.0, 8.0]);
+    }
+
+    #[test]
+    fn from_conventional() {
+        let matrix = Skyline::from(Conventional::from_vec(
+            4,
+            matrix![
+                1.0, 2.0, 0.0, 5.0;
+                2.0, 3.0, 0.0, 0.0;
+                0.0, 0.0, 4.0, 7.0;
+                5.0, 0.0, 7.0, 8.0;
+            ],
+        ));
+        assert_eq!(matrix.offsets, vec![0, 1, 3, 4, 8]);
+        assert_eq!(matrix.values, vec![1.0, 2.0, 3.0, 4.0, 5.0, 0.0, 7.0, 8.0]);
+    }
+
+    #[test]
+    fn into_conventional() {
+        let matrix = Conventional::from_vec(
+            4,
+            matrix![
+                1.0, 2.0, 0.0, 5.0;
+                2.0, 3.0, 0.0, 0.0;
+                0.0, 0.0, 4.0, 7.0;
+                5.0, 0.0, 7.0, 8.0;
+            ],
+        );
+        assert_eq!(Conventional::from(Skyline::from(matrix.clone())), matrix);
+    }
+}
@@ -0,0 +1,174 @@
+use num_traits::Float;
+
+use decomposition::{Condition, Solve};
+use format::Skyline;
+use {Element, Number, Result};
+
+/// The LDLᵀ decomposition of a skyline matrix.
+///
+/// The decomposition is computed without pivoting, and the factors preserve
+/// the profile of the original matrix.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Ldl<T: Element> {
+    /// The factors stored in the skyline format such that the elements above
+    /// the diagonal are those of `Lᵀ`, whose diagonal is implicitly one, and
+    /// the diagonal elements are those of `D`.
+    pub factors: Skyline<T>,
+}
+
+impl<T> Skyline<T>
+where
+    T: Element + Number,
+{
+    /// Perform the LDLᵀ decomposition.
+    ///
+    /// The function fails when a zero pivot is encountered.
+    pub fn ldl(&self) -> Result<Ldl<T>> {
+        let mut factors = validate!(self).clone();
+        {
+            let Skyline {
+                size,
+                ref mut values,
+                ref offsets,
+            } = factors;
+            for j in 0..size {
+                let (fj, oj) = (first!(offsets, j), offsets[j]);
+                for i in (fj + 1)..j {
+                    let (fi, oi) = (first!(offsets, i), offsets[i]);
+                    let mut sum = Element::zero();
+                    for k in max!(fi, fj)..i {
+                        sum = sum + values[oi + k - fi] * values[oj + k - fj];
+                    }
+                    values[oj + i - fj] = values[oj + i - fj] - sum;
+                }
+                let mut pivot = values[offsets[j + 1] - 1];
+                for i in fj..j {
+                    let value = values[oj + i - fj];
+                    let factor = value / values[offsets[i + 1] - 1];
+                    values[oj + i - fj] = factor;
+                    pivot = pivot - factor * value;
+                }
+                if Element::is_zero(&pivot) {
+                    raise!(format!("encountered a zero pivot in column {}", j));
+                }
+                values[offsets[j + 1] - 1] = pivot;
+            }
+        }
+        Ok(Ldl { factors })
+    }
+}
+
+impl<T> Ldl<T>
+where
+    T: Element + Number,
+{
+    /// Compute the determinant.
+    pub fn determinant(&self) -> T {
+        let Skyline {
+            size,
+            ref values,
+            ref offsets,
+        } = self.factors;
+        (0..size).fold(Element::one(), |product, j| {
+            product * values[offsets[j + 1] - 1]
+        })
+    }
+}
+
+impl<T> Condition<T> for Ldl<T>
+where
+    T: Element + Float,
+{
+    #[inline]
+    fn order(&self) -> usize {
+        self.factors.size
+    }
+}
+
+impl<T> Solve<T> for Ldl<T>
+where
+    T: Element + Number,
+{
+    fn solve(&self, right: &mut [T]) {
+        let Skyline {
+            size,
+            ref values,
+            ref offsets,
+        } = self.factors;
+        if size == 0 {
+            return;
+        }
+        debug_assert_eq!(right.len() % size, 0);
+        for right in right.chunks_mut(size) {
+            for j in 0..size {
+                let (fj, oj) = (first!(offsets, j), offsets[j]);
+                let mut sum = Element::zero();
+                for i in fj..j {
+                    sum = sum + values[oj + i - fj] * right[i];
+                }
+                right[j] = right[j] - sum;
+            }
+            for j in 0..size {
+                right[j] = right[j] / values[offsets[j + 1] - 1];
+            }
+            for j in (0..size).rev() {
+                let (fj, oj) = (first!(offsets, j), offsets[j]);
+                let value = right[j];
+                for i in fj..j {
+                    right[i] = right[i] - values[oj + i - fj] * value;
+                }
+            }
+        }
+    }
+
+    #[inline]
+    fn solve_transpose(&self, right: &mut [T]) {
+        self.solve(right)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use assert;
+    use prelude::*;
+
+    #[test]
+    fn ldl() {
+        let matrix = Skyline::from(Conventional::from_vec(
+            4,
+            matrix![
+                4.0, 2.0, 0.0, 1.0;
+                2.0, 5.0, 0.0, 0.0;
+                0.0, 0.0, 3.0, 1.0;
+                1.0, 0.0, 1.0, 6.0;
+            ],
+        ));
+        let ldl = matrix.ldl().unwrap();
+        assert_eq!(ldl.factors.offsets, matrix.offsets);
+        assert::close(
+            &ldl.factors.values,
+            &[4.0, 0.5, 4.0, 3.0, 0.25, -0.125, 1.0 / 3.0, 257.0 / 48.0],
+            1e-14,
+        );
+        assert::close(&[ldl.determinant()], &[257.0], 1e-12);
+        let mut right = vec![7.0, 7.0, 4.0, 8.0, 14.0, 14.0, 8.0, 16.0];
+        ldl.solve(&mut right);
+        assert::close(&right, &[1.0, 1.0, 1.0, 1.0, 2.0, 2.0, 2.0, 2.0], 1e-14);
+    }
+
+    #[test]
+    fn ldl_indefinite() {
+        let matrix = Skyline::from(Conventional::from_vec(2, vec![1.0, 2.0, 2.0, 1.0]));
+        let ldl = matrix.ldl().unwrap();
+        assert_eq!(&ldl.factors.values, &[1.0, 2.0, -3.0]);
+        let mut right = vec![3.0, 3.0];
+        ldl.solve_transpose(&mut right);
+        assert::close(&right, &[1.0, 1.0], 1e-14);
+    }
+
+    #[test]
+    fn ldl_singular() {
+        let matrix = Skyline::from(Conventional::from_vec(2, vec![1.0, 1.0, 1.0, 1.0]));
+        assert!(matrix.ldl().is_err());
+    }
+}
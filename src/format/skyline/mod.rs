@@ -0,0 +1,203 @@
+//! The skyline format.
+//!
+//! The format is suitable for symmetric matrices whose nonzero elements are
+//! clustered around the diagonal with a varying width, which is typical for
+//! finite-element discretizations. For each column, the format stores the
+//! elements from the first nonzero one down to the diagonal; see the
+//! [skyline format][1] for further details.
+//!
+//! [1]: http://netlib.org/linalg/html_templates/node96.html
+
+use format::sum;
+use {Element, Matrix, Position, Result, Size};
+
+/// A skyline matrix.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Skyline<T: Element> {
+    /// The number of rows or columns.
+    pub size: usize,
+    /// The values of the upper triangle stored by columns such that each
+    /// column is stored from its first stored element down to the diagonal.
+    pub values: Vec<T>,
+    /// The offsets of columns such that the values of the `j`th column are
+    /// stored starting from `values[offsets[j]]` and ending with the diagonal
+    /// element at `values[offsets[j + 1] - 1]`. The vector has one additional
+    /// element, which is always equal to the number of stored elements.
+    pub offsets: Vec<usize>,
+}
+
+macro_rules! new(
+    ($size:expr, $values:expr, $offsets:expr) => (
+        Skyline { size: $size, values: $values, offsets: $offsets }
+    );
+);
+
+macro_rules! first(
+    ($offsets:expr, $column:expr) => ({
+        let (offsets, column) = (&$offsets, $column);
+        column + 1 + offsets[column] - offsets[column + 1]
+    });
+);
+
+mod convert;
+mod decomposition;
+
+pub use self::decomposition::Ldl;
+
+#[cfg(debug_assertions)]
+impl<T: Element> ::format::Validate for Skyline<T> {
+    fn validate(&self) {
+        assert_eq!(self.size + 1, self.offsets.len());
+        assert_eq!(self.offsets[self.size], self.values.len());
+        for j in 0..self.size {
+            assert!(self.offsets[j] < self.offsets[j + 1]);
+            assert!(self.offsets[j + 1] - self.offsets[j] <= j + 1);
+        }
+    }
+}
+
+size!(Skyline, size, size);
+
+impl<T: Element> Skyline<T> {
+    /// Create a zero matrix with only the diagonal stored.
+    pub fn new<S: Size>(size: S) -> Self {
+        let (rows, _columns) = size.dimensions();
+        debug_assert!(rows == _columns);
+        new!(rows, vec![T::zero(); rows], (0..(rows + 1)).collect())
+    }
+
+    /// Create a zero matrix with a specific profile.
+    ///
+    /// The `j`th element of `heights` is the number of stored elements above
+    /// the diagonal in the `j`th column.
+    pub fn with_heights(heights: &[usize]) -> Self {
+        let size = heights.len();
+        let mut offsets = vec![0; size + 1];
+        for (j, &height) in heights.iter().enumerate() {
+            debug_assert!(height <= j);
+            offsets[j + 1] = offsets[j] + height + 1;
+        }
+        new!(size, vec![T::zero(); offsets[size]], offsets)
+    }
+
+    /// Create a zero matrix with a specific profile checking that no column
+    /// extends above the first row.
+    pub fn try_with_heights(heights: &[usize]) -> Result<Self> {
+        for (j, &height) in heights.iter().enumerate() {
+            if height > j {
+                raise!(format!(
+                    "the height of column {} should be at most {} but it is {}",
+                    j, j, height
+                ));
+            }
+        }
+        Ok(Skyline::with_heights(heights))
+    }
+
+    /// Create a matrix from its parts checking that they are consistent.
+    ///
+    /// The offsets should start with zero and end with the number of values,
+    /// and each column should contain at least its diagonal element and at
+    /// most all the elements above it.
+    pub fn from_parts(size: usize, values: Vec<T>, offsets: Vec<usize>) -> Result<Self> {
+        if offsets.len() != sum(size, 1)? || offsets[0] != 0 || offsets[size] != values.len() {
+            raise!(format!(
+                "there should be {} offsets starting with 0 and ending with {}",
+                size + 1,
+                values.len()
+            ));
+        }
+        for j in 0..size {
+            if offsets[j] >= offsets[j + 1] || offsets[j + 1] - offsets[j] > j + 1 {
+                raise!(format!("the height of column {} is invalid", j));
+            }
+        }
+        Ok(new!(size, values, offsets))
+    }
+
+    /// Return the index of the first stored row of a column.
+    #[inline]
+    pub fn first(&self, column: usize) -> usize {
+        first!(self.offsets, column)
+    }
+
+    /// Read an element.
+    pub fn get<P: Position>(&self, position: P) -> T {
+        let (mut i, mut j) = position.coordinates();
+        debug_assert!(i < self.size && j < self.size);
+        if i > j {
+            ::std::mem::swap(&mut i, &mut j);
+        }
+        let first = self.first(j);
+        if i < first {
+            T::zero()
+        } else {
+            self.values[self.offsets[j] + i - first]
+        }
+    }
+}
+
+impl<T: Element> Matrix for Skyline<T> {
+    type Element = T;
+
+    fn nonzeros(&self) -> usize {
+        let mut count = 0;
+        for j in 0..self.size {
+            let (start, finish) = (self.offsets[j], self.offsets[j + 1]);
+            for k in start..finish {
+                if !self.values[k].is_zero() {
+                    count += if k + 1 == finish { 1 } else { 2 };
+                }
+            }
+        }
+        count
+    }
+
+    #[inline]
+    fn zero<S: Size>(size: S) -> Self {
+        Skyline::new(size)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use prelude::*;
+
+    #[test]
+    fn from_parts() {
+        let matrix = Skyline::from_parts(3, vec![1.0, 2.0, 3.0, 4.0], vec![0, 1, 2, 4]).unwrap();
+        assert_eq!(matrix, new!(3, vec![1.0, 2.0, 3.0, 4.0], vec![0, 1, 2, 4]));
+        assert!(Skyline::from_parts(3, vec![1.0; 4], vec![0, 1, 2, 3]).is_err());
+        assert!(Skyline::from_parts(3, vec![1.0; 4], vec![0, 2, 3, 4]).is_err());
+        assert!(Skyline::from_parts(3, vec![1.0; 4], vec![0, 1, 1, 4]).is_err());
+    }
+
+    #[test]
+    fn try_with_heights() {
+        let matrix = Skyline::<f64>::try_with_heights(&[0, 1, 1]).unwrap();
+        assert_eq!(matrix.offsets, vec![0, 1, 3, 5]);
+        assert!(Skyline::<f64>::try_with_heights(&[0, 2, 1]).is_err());
+    }
+
+    #[test]
+    fn get() {
+        let matrix = Skyline::with_heights(&[0, 1, 0, 3]);
+        assert_eq!(matrix.offsets, vec![0, 1, 3, 4, 8]);
+        let mut matrix = matrix;
+        matrix.values = vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0];
+        let expected = matrix![
+            1.0, 2.0, 0.0, 5.0;
+            2.0, 3.0, 0.0, 6.0;
+            0.0, 0.0, 4.0, 7.0;
+            5.0, 6.0, 7.0, 8.0;
+        ];
+        for i in 0..4 {
+            for j in 0..4 {
+                assert_eq!(matrix.get((i, j)), expected[j * 4 + i]);
+            }
+        }
+        assert_eq!(matrix.first(3), 0);
+        assert_eq!(matrix.first(2), 2);
+        assert_eq!(matrix.nonzeros(), 12);
+    }
+}
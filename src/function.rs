@@ -0,0 +1,499 @@
+//! Matrix functions.
+//!
+//! The functions are defined for square conventional matrices. Symmetric
+//! matrices are processed via their eigendecompositions when the
+//! `acceleration` feature is enabled.
+
+use std::cmp::max;
+
+#[cfg(feature = "acceleration")]
+use decomposition::SymmetricEigen;
+use format::Conventional;
+use operation::Multiply;
+#[cfg(feature = "acceleration")]
+use operation::Transpose;
+use Result;
+
+const ITERATIONS: usize = 100;
+
+/// Compute the exponential.
+///
+/// The exponential is computed using a diagonal Padé approximation with
+/// scaling and squaring as described in “Matrix Computations” by Golub and
+/// Van Loan.
+pub fn exp(matrix: &Conventional<f64>) -> Result<Conventional<f64>> {
+    const DEGREE: usize = 6;
+    square(matrix)?;
+    #[cfg(feature = "acceleration")]
+    {
+        if let Some(result) = spectral(matrix, |value| Ok(value.exp())) {
+            return result;
+        }
+    }
+    let n = matrix.rows;
+    let norm = matrix.norm_infinity();
+    let scale = if norm > 0.0 {
+        max(norm.log2().floor() as i32 + 2, 0)
+    } else {
+        0
+    };
+    let mut matrix = matrix.clone();
+    let factor = 0.5f64.powi(scale);
+    for value in matrix.values.iter_mut() {
+        *value *= factor;
+    }
+    let mut power = matrix.clone();
+    let mut coefficient = 0.5;
+    let mut numerator = identity(n);
+    let mut denominator = identity(n);
+    add(&mut numerator, coefficient, &power);
+    add(&mut denominator, -coefficient, &power);
+    for k in 2..(DEGREE + 1) {
+        coefficient *= (DEGREE - k + 1) as f64 / (k * (2 * DEGREE - k + 1)) as f64;
+        power = matrix.multiply(&power);
+        add(&mut numerator, coefficient, &power);
+        let sign = if k % 2 == 0 { 1.0 } else { -1.0 };
+        add(&mut denominator, sign * coefficient, &power);
+    }
+    let mut result = denominator.inverse()?.multiply(&numerator);
+    for _ in 0..scale {
+        result = result.multiply(&result);
+    }
+    Ok(result)
+}
+
+/// Compute the principal logarithm.
+///
+/// The logarithm is computed using inverse scaling and squaring: square roots
+/// are taken until the matrix is close to the identity, and the logarithm of
+/// the result is evaluated via a Padé approximation in the partial-fraction
+/// form. The function fails when the matrix has eigenvalues on the closed
+/// negative real axis.
+pub fn log(matrix: &Conventional<f64>) -> Result<Conventional<f64>> {
+    const DEGREE: usize = 8;
+    const RADIUS: f64 = 0.25;
+    square(matrix)?;
+    #[cfg(feature = "acceleration")]
+    {
+        if let Some(result) = spectral(matrix, |value| {
+            if value <= 0.0 {
+                raise!("the matrix has a nonpositive eigenvalue");
+            }
+            Ok(value.ln())
+        }) {
+            return result;
+        }
+    }
+    let n = matrix.rows;
+    let mut matrix = matrix.clone();
+    let mut scale = 0;
+    loop {
+        let mut difference = matrix.clone();
+        add(&mut difference, -1.0, &identity(n));
+        if difference.norm_one() <= RADIUS {
+            matrix = difference;
+            break;
+        }
+        if scale == ITERATIONS {
+            raise!("failed to converge");
+        }
+        matrix = sqrt(&matrix)?;
+        scale += 1;
+    }
+    let mut result = Conventional::new(n);
+    for (node, weight) in legendre(DEGREE) {
+        let mut denominator = identity(n);
+        add(&mut denominator, node, &matrix);
+        add(
+            &mut result,
+            weight,
+            &matrix.multiply(&denominator.inverse()?),
+        );
+    }
+    let factor = 2f64.powi(scale as i32);
+    for value in result.values.iter_mut() {
+        *value *= factor;
+    }
+    Ok(result)
+}
+
+/// Compute the principal square root.
+///
+/// The square root is computed using the iteration of Denman and Beavers,
+/// which requires the matrix to be invertible. The function fails when the
+/// matrix has eigenvalues on the closed negative real axis, including zero.
+pub fn sqrt(matrix: &Conventional<f64>) -> Result<Conventional<f64>> {
+    const TOLERANCE: f64 = 1e-14;
+    square(matrix)?;
+    #[cfg(feature = "acceleration")]
+    {
+        if let Some(result) = spectral(matrix, |value| {
+            if value <= 0.0 {
+                raise!("the matrix has a nonpositive eigenvalue");
+            }
+            Ok(value.sqrt())
+        }) {
+            return result;
+        }
+    }
+    let n = matrix.rows;
+    let mut root = matrix.clone();
+    let mut inverse = identity(n);
+    let mut previous = f64::INFINITY;
+    for _ in 0..ITERATIONS {
+        let mut next = inverse.inverse()?;
+        add(&mut next, 1.0, &root);
+        let mut other = root.inverse()?;
+        add(&mut other, 1.0, &inverse);
+        inverse = other;
+        for value in next.values.iter_mut().chain(inverse.values.iter_mut()) {
+            *value *= 0.5;
+        }
+        let mut difference = next.clone();
+        add(&mut difference, -1.0, &root);
+        root = next;
+        // Rounding errors can prevent the iteration from reaching the
+        // tolerance, in which case it is stopped once it stagnates.
+        let (difference, norm) = (difference.norm_one(), root.norm_one());
+        if difference <= TOLERANCE * norm || (difference <= 1e-7 * norm && difference >= previous) {
+            return Ok(root);
+        }
+        previous = difference;
+    }
+    raise!("failed to converge");
+}
+
+/// Compute an integer power.
+///
+/// The power is computed by repeated squaring. A negative exponent requires
+/// the matrix to be invertible.
+pub fn powi(matrix: &Conventional<f64>, exponent: i32) -> Result<Conventional<f64>> {
+    square(matrix)?;
+    let mut base = if exponent < 0 {
+        matrix.inverse()?
+    } else {
+        matrix.clone()
+    };
+    let mut exponent = (exponent as i64).abs();
+    let mut result = identity(matrix.rows);
+    while exponent > 0 {
+        if exponent % 2 == 1 {
+            result = result.multiply(&base);
+        }
+        exponent /= 2;
+        if exponent > 0 {
+            base = base.multiply(&base);
+        }
+    }
+    Ok(result)
+}
+
+/// Compute a real power.
+///
+/// An integer exponent is handled by `powi`. Otherwise, the integer part of
+/// the exponent is handled by `powi`, and the fractional part via the
+/// exponential and logarithm. The function fails when the exponent is not
+/// integer and the matrix has eigenvalues on the closed negative real axis,
+/// including zero.
+pub fn powf(matrix: &Conventional<f64>, exponent: f64) -> Result<Conventional<f64>> {
+    square(matrix)?;
+    if exponent.fract() == 0.0 && exponent.abs() <= i32::MAX as f64 {
+        return powi(matrix, exponent as i32);
+    }
+    #[cfg(feature = "acceleration")]
+    {
+        if let Some(result) = spectral(matrix, |value| {
+            if value <= 0.0 {
+                raise!("the matrix has a nonpositive eigenvalue");
+            }
+            Ok(value.powf(exponent))
+        }) {
+            return result;
+        }
+    }
+    let integer = exponent.floor();
+    let fraction = exponent - integer;
+    let result = powi(matrix, integer as i32)?;
+    if fraction == 0.0 {
+        return Ok(result);
+    }
+    let mut logarithm = log(matrix)?;
+    for value in logarithm.values.iter_mut() {
+        *value *= fraction;
+    }
+    Ok(result.multiply(&exp(&logarithm)?))
+}
+
+fn add(matrix: &mut Conventional<f64>, alpha: f64, other: &Conventional<f64>) {
+    for (value, &other) in matrix.values.iter_mut().zip(other.values.iter()) {
+        *value += alpha * other;
+    }
+}
+
+fn identity(n: usize) -> Conventional<f64> {
+    let mut matrix = Conventional::new(n);
+    for i in 0..n {
+        matrix.values[i * n + i] = 1.0;
+    }
+    matrix
+}
+
+// Compute the nodes and weights of the Gauss–Legendre quadrature on [0, 1].
+fn legendre(degree: usize) -> Vec<(f64, f64)> {
+    use std::f64::consts::PI;
+    (0..degree)
+        .map(|i| {
+            let mut x = (PI * (i as f64 + 0.75) / (degree as f64 + 0.5)).cos();
+            let mut derivative = 0.0;
+            for _ in 0..ITERATIONS {
+                let (mut current, mut previous) = (1.0, 0.0);
+                for k in 1..(degree + 1) {
+                    let k = k as f64;
+                    let next = ((2.0 * k - 1.0) * x * current - (k - 1.0) * previous) / k;
+                    previous = current;
+                    current = next;
+                }
+                derivative = degree as f64 * (x * current - previous) / (x * x - 1.0);
+                let step = current / derivative;
+                x -= step;
+                if step.abs() <= 1e-16 {
+                    break;
+                }
+            }
+            let weight = 2.0 / ((1.0 - x * x) * derivative * derivative);
+            ((x + 1.0) / 2.0, weight / 2.0)
+        })
+        .collect()
+}
+
+#[cfg(feature = "acceleration")]
+fn spectral<F>(matrix: &Conventional<f64>, map: F) -> Option<Result<Conventional<f64>>>
+where
+    F: Fn(f64) -> Result<f64>,
+{
+    let n = matrix.rows;
+    for j in 0..n {
+        for i in (j + 1)..n {
+            if matrix.values[j * n + i] != matrix.values[i * n + j] {
+                return None;
+            }
+        }
+    }
+    Some(
+        SymmetricEigen::decompose(matrix).and_then(|(vectors, values)| {
+            let mut scaled = vectors.clone();
+            for (j, &value) in values.iter().enumerate() {
+                let value = map(value)?;
+                for element in scaled.values[(j * n)..((j + 1) * n)].iter_mut() {
+                    *element *= value;
+                }
+            }
+            Ok(scaled.multiply(&vectors.transpose()))
+        }),
+    )
+}
+
+fn square(matrix: &Conventional<f64>) -> Result<()> {
+    if matrix.rows != matrix.columns {
+        raise!(format!(
+            "the matrix should be square but it is {} × {}",
+            matrix.rows, matrix.columns
+        ));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use assert;
+
+    use prelude::*;
+
+    #[test]
+    fn exp() {
+        let matrix = Conventional::from_vec(
+            2,
+            matrix![
+                 0.0, 1.0;
+                -1.0, 0.0;
+            ],
+        );
+        let (cos, sin) = (1f64.cos(), 1f64.sin());
+        assert::close(
+            &*super::exp(&matrix).unwrap(),
+            &*Conventional::from_vec(
+                2,
+                matrix![
+                     cos, sin;
+                    -sin, cos;
+                ],
+            ),
+            1e-14,
+        );
+
+        let matrix = Conventional::from_vec(
+            2,
+            matrix![
+                10.0, 20.0;
+                 0.0,  0.0;
+            ],
+        );
+        let value = 10f64.exp();
+        assert::close(
+            &*super::exp(&matrix).unwrap(),
+            &*Conventional::from_vec(
+                2,
+                matrix![
+                    value, 2.0 * (value - 1.0);
+                      0.0, 1.0;
+                ],
+            ),
+            1e-7,
+        );
+        assert!(super::exp(&Conventional::new((2, 3))).is_err());
+    }
+
+    #[test]
+    fn log() {
+        let matrix = Conventional::from_vec(
+            2,
+            matrix![
+                1.0, 1.0;
+                0.0, 1.0;
+            ],
+        );
+        assert::close(
+            &*super::log(&matrix).unwrap(),
+            &*Conventional::from_vec(
+                2,
+                matrix![
+                    0.0, 1.0;
+                    0.0, 0.0;
+                ],
+            ),
+            1e-14,
+        );
+
+        let matrix = Conventional::from_vec(
+            2,
+            matrix![
+                 0.1, 0.5;
+                -0.3, 0.2;
+            ],
+        );
+        let result = super::log(&super::exp(&matrix).unwrap()).unwrap();
+        assert::close(&*result, &*matrix, 1e-13);
+    }
+
+    #[test]
+    fn powf() {
+        let matrix = Conventional::from_vec(
+            2,
+            matrix![
+                4.0, 1.0;
+                0.0, 9.0;
+            ],
+        );
+        assert::close(
+            &*super::powf(&matrix, 1.5).unwrap(),
+            &*Conventional::from_vec(
+                2,
+                matrix![
+                    8.0, 3.8;
+                    0.0, 27.0;
+                ],
+            ),
+            1e-12,
+        );
+        assert::close(
+            &*super::powf(&matrix, -0.5).unwrap(),
+            &*Conventional::from_vec(
+                2,
+                matrix![
+                    0.5, -1.0 / 30.0;
+                    0.0, 1.0 / 3.0;
+                ],
+            ),
+            1e-14,
+        );
+
+        let matrix = Conventional::from_vec(
+            2,
+            matrix![
+                -1.0, 2.0;
+                 2.0, 0.0;
+            ],
+        );
+        assert_eq!(
+            super::powf(&matrix, 2.0).unwrap(),
+            super::powi(&matrix, 2).unwrap()
+        );
+        assert!(super::powf(&matrix, 0.5).is_err());
+    }
+
+    #[test]
+    fn powi() {
+        let matrix = Conventional::from_vec(
+            2,
+            matrix![
+                1.0, 1.0;
+                0.0, 1.0;
+            ],
+        );
+        assert_eq!(
+            super::powi(&matrix, 5).unwrap(),
+            Conventional::from_vec(
+                2,
+                matrix![
+                    1.0, 5.0;
+                    0.0, 1.0;
+                ],
+            )
+        );
+        assert_eq!(
+            super::powi(&matrix, -2).unwrap(),
+            Conventional::from_vec(
+                2,
+                matrix![
+                    1.0, -2.0;
+                    0.0,  1.0;
+                ],
+            )
+        );
+        assert_eq!(
+            super::powi(&matrix, 0).unwrap(),
+            Conventional::from_vec(2, vec![1.0, 0.0, 0.0, 1.0])
+        );
+    }
+
+    #[test]
+    fn sqrt() {
+        let matrix = Conventional::from_vec(
+            2,
+            matrix![
+                4.0, 1.0;
+                0.0, 9.0;
+            ],
+        );
+        assert::close(
+            &*super::sqrt(&matrix).unwrap(),
+            &*Conventional::from_vec(
+                2,
+                matrix![
+                    2.0, 0.2;
+                    0.0, 3.0;
+                ],
+            ),
+            1e-14,
+        );
+
+        let matrix = Conventional::from_vec(
+            2,
+            matrix![
+                1.0, 1.0;
+                1.0, 1.0;
+            ],
+        );
+        assert!(super::sqrt(&matrix).is_err());
+    }
+}
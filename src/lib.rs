@@ -7,14 +7,29 @@
 //! * [Banded][banded], suitable for matrices with a small number of
 //!   superdiagonals and/or subdiagonals;
 //!
+//! * [Block][block], suitable for sparse matrices composed of small dense
+//!   blocks;
+//!
 //! * [Compressed][compressed], suitable for generic sparse matrices;
 //!
 //! * [Conventional][conventional], suitable for dense matrices;
 //!
-//! * [Diagonal][diagonal], suitable for diagonal matrices; and
+//! * [Diagonal][diagonal], suitable for diagonal matrices;
+//!
+//! * [ELLPACK][ellpack], suitable for sparse matrices whose rows have nearly
+//!   the same number of nonzero elements;
+//!
+//! * [Multidiagonal][multidiagonal], suitable for matrices with a few
+//!   nonadjacent nonzero diagonals;
 //!
 //! * [Packed][packed], suitable for symmetric, Hermitian, and triangular
-//!   matrices.
+//!   matrices;
+//!
+//! * [Rectangular full packed][rfp], suitable for symmetric, Hermitian, and
+//!   triangular matrices processed by blocks; and
+//!
+//! * [Skyline][skyline], suitable for symmetric matrices with a variable
+//!   bandwidth.
 //!
 //! ## Example
 //!
@@ -40,10 +55,15 @@
 //! ```
 //!
 //! [banded]: format/banded/index.html
+//! [block]: format/block/index.html
 //! [compressed]: format/compressed/index.html
 //! [conventional]: format/conventional/index.html
 //! [diagonal]: format/diagonal/index.html
+//! [ellpack]: format/ellpack/index.html
+//! [multidiagonal]: format/multidiagonal/index.html
 //! [packed]: format/packed/index.html
+//! [rfp]: format/rfp/index.html
+//! [skyline]: format/skyline/index.html
 
 #[cfg(test)]
 extern crate assert;
@@ -143,7 +163,6 @@ pub struct Error(String);
 /// A result.
 pub type Result<T> = std::result::Result<T, Error>;
 
-#[cfg(feature = "acceleration")]
 macro_rules! raise(
     ($message:expr) => (
         return Err(::Error($message.to_string()));
@@ -174,5 +193,6 @@ pub use size::Size;
 
 pub mod decomposition;
 pub mod format;
+pub mod function;
 pub mod operation;
 pub mod prelude;
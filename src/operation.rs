@@ -1,5 +1,39 @@
 //! Basic operations.
 
+use Result;
+
+/// An addition that fails when the operands are incompatible.
+pub trait CheckedAdd<Right: ?Sized, Output> {
+    /// Perform the addition.
+    fn checked_add(&self, right: &Right) -> Result<Output>;
+}
+
+/// A multiplication that fails when the operands are incompatible.
+pub trait CheckedMul<Right: ?Sized, Output> {
+    /// Perform the multiplication.
+    fn checked_mul(&self, right: &Right) -> Result<Output>;
+}
+
+/// A subtraction that fails when the operands are incompatible.
+pub trait CheckedSub<Right: ?Sized, Output> {
+    /// Perform the subtraction.
+    fn checked_sub(&self, right: &Right) -> Result<Output>;
+}
+
+/// The Kronecker product.
+pub trait Kronecker<Right: ?Sized, Output> {
+    /// Compute the product.
+    ///
+    /// The function panics when the dimensions of the result overflow.
+    fn kronecker(&self, right: &Right) -> Output;
+}
+
+/// The Kronecker sum of two square matrices.
+pub trait KroneckerSum<Right: ?Sized, Output> {
+    /// Compute the sum.
+    fn kronecker_sum(&self, right: &Right) -> Result<Output>;
+}
+
 /// A multiplication.
 pub trait Multiply<Right: ?Sized, Output> {
     /// Perform the multiplication.
@@ -6,22 +6,41 @@ pub use Position;
 pub use Size;
 
 pub use format::banded;
+pub use format::block;
 pub use format::compressed;
 pub use format::conventional;
 pub use format::diagonal;
+pub use format::ellpack;
+pub use format::multidiagonal;
 pub use format::packed;
+pub use format::rfp;
+pub use format::skyline;
 
 pub use format::banded::Banded;
+pub use format::block::Block;
 pub use format::compressed::Compressed;
 pub use format::conventional::Conventional;
 pub use format::diagonal::Diagonal;
+pub use format::ellpack::Ellpack;
+pub use format::multidiagonal::Multidiagonal;
 pub use format::packed::Packed;
+pub use format::rfp::Rfp;
+pub use format::skyline::Skyline;
+pub use format::Axis;
+pub use format::Pattern;
 
+pub use operation::CheckedAdd;
+pub use operation::CheckedMul;
+pub use operation::CheckedSub;
+pub use operation::Kronecker;
+pub use operation::KroneckerSum;
 pub use operation::Multiply;
 pub use operation::MultiplyInto;
 pub use operation::MultiplySelf;
 pub use operation::ScaleSelf;
 pub use operation::Transpose;
 
+pub use decomposition::Condition;
 pub use decomposition::SingularValue;
+pub use decomposition::Solve;
 pub use decomposition::SymmetricEigen;